@@ -0,0 +1,35 @@
+@group(0) @binding(0)
+var input_tex: texture_2d<f32>;
+@group(0) @binding(1)
+var input_sampler: sampler;
+
+struct PostUniforms {
+    resolution: vec2<f32>,
+    time: f32,
+    delta: f32,
+    params: array<vec4<f32>, 4>,
+}; // end PostUniforms
+
+@group(0) @binding(2)
+var<uniform> post: PostUniforms;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}; // end VertexOutput
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    var positions = array<vec2<f32>, 3>(
+        vec2(-1.0, -3.0),
+        vec2(3.0, 1.0),
+        vec2(-1.0, 1.0),
+    );
+    let pos = positions[index];
+    var out: VertexOutput;
+    out.position = vec4<f32>(pos, 0.0, 1.0);
+    out.uv = vec2(0.5 * (pos.x + 1.0), 1.0 - 0.5 * (pos.y + 1.0));
+    return out;
+} // end vertex main
+
+// User fragment shader follows, it must define fs_main and may bind extra textures at @group(1)
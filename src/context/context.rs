@@ -2,52 +2,244 @@
 use std::sync::Arc;
 
 use vello::{peniko::color::AlphaColor, wgpu};
-use winit::window::Window;
+use winit::{event::{MouseScrollDelta, WindowEvent}, window::Window};
 
 use crate::context::{Gpu};
 use crate::prelude::*;
 
+pub const MIN_RENDER_SCALE: f64 = 0.25;
+pub const MAX_RENDER_SCALE: f64 = 4.0;
+
 pub struct Context {
 	pub window: Arc<Window>,
 	pub gpu: Gpu,
 	pub scene: Scene,
 	pub state: State,
+	pub post: PostChain,
+	pub hooks: RenderHooks,
+	pub viewports: Vec<Viewport>,
+	root: vello::Scene,
 } // end struct Context
 
 impl Context {
 	pub async fn new ( window: Arc<Window> ) -> Self {
 		let gpu = Gpu::new(window.clone()).await;
-		return Self { window: window.clone(), gpu, scene: Scene::new(), state: State::new(window.inner_size()) };
+		let mut state = State::new(window.inner_size());
+		state.scale_factor = window.scale_factor();
+		return Self { window: window.clone(), gpu, scene: Scene::new(), state, post: PostChain::new(), hooks: RenderHooks::new(), viewports: Vec::new(), root: vello::Scene::new() };
 	} // end fn new
+	#[cfg(not(target_arch = "wasm32"))]
+	pub fn from_window(window: Arc<Window>) -> Self {
+		let mut context = async_std::task::block_on(Self::new(window));
+		context.resize();
+		return context;
+	} // end fn from_window
+	// Applies the rendering options of a WindowConfig, the window options are left to whoever created the window
+	pub fn configure(&mut self, config: &WindowConfig) {
+		self.state.coordinates = config.coordinates;
+		self.state.render_scale = config.render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+		self.post.hot_reload = config.hot_reload;
+		self.set_nearest_filtering(config.nearest_filtering);
+		self.set_virtual_resolution(config.virtual_resolution, config.scaling);
+	} // end fn configure
+	// Feeds a window event into the context, for hosts that run their own winit event loop
+	pub fn handle_window_event(&mut self, event: WindowEvent) {
+		match event {
+			WindowEvent::CloseRequested => { self.state.exiting = true; }
+			WindowEvent::Resized(_size) => {
+				if self.window.is_resizable() == false { self.window.set_maximized(false); }
+				else { self.resize(); }
+			} // end Resized
+			WindowEvent::ScaleFactorChanged { scale_factor: _, inner_size_writer: _ } => { self.resize(); }
+			WindowEvent::CursorMoved { device_id: _, position } => { self.state.cursor_event(position); self.update_viewport_mouse(); }
+			WindowEvent::CursorLeft { device_id: _ } => { self.state.mouse = None; self.update_viewport_mouse(); }
+			WindowEvent::KeyboardInput { device_id: _, event, is_synthetic: _ } => { self.state.keyboard_event(event); }
+			WindowEvent::MouseInput { device_id: _, state, button } => { self.state.button_event(button, state); }
+			WindowEvent::MouseWheel { device_id: _, delta, phase: _ } => {
+				match delta {
+					MouseScrollDelta::LineDelta(x, y) => { self.state.wheel = y; self.state.wheel_x = x; }
+					MouseScrollDelta::PixelDelta(pos) => { self.state.wheel = pos.y as f32 / 100.0; self.state.wheel_x = pos.x as f32 / 100.0; }
+				} // end match delta
+			} // end MouseWheel
+			_ => {} // default case
+		} // end match event
+	} // end fn handle_window_event
+	// Runs one frame: the user callback, the state update and the render, then requests the next redraw
+	pub fn frame(&mut self, eloop: impl FnOnce(&mut Context)) {
+		self.update_viewport_mouse();
+		eloop(self);
+		self.state.update();
+		self.render();
+		self.window.request_redraw();
+	} // end fn frame
+	pub fn add_viewport(&mut self, viewport: Viewport) -> usize {
+		self.viewports.push(viewport);
+		return self.viewports.len() - 1;
+	} // end fn add_viewport
+	// Returns the topmost visible viewport containing the point
+	pub fn viewport_at(&self, point: impl Into<Point>) -> Option<usize> {
+		let point = point.into();
+		return self.viewports.iter().rposition(|viewport| viewport.visible && viewport.contains(point));
+	} // end fn viewport_at
+	fn update_viewport_mouse(&mut self) {
+		let hovered = self.state.mouse.and_then(|mouse| self.viewport_at(mouse).map(|index| (index, mouse)));
+		self.state.mouse_viewport = hovered.map(|(index, _)| index);
+		self.state.viewport_mouse = hovered.map(|(index, mouse)| self.viewports[index].to_local(mouse));
+	} // end fn update_viewport_mouse
 	pub fn resize(&mut self) {
-		self.state.resolution = self.window.inner_size().into();
+		self.state.scale_factor = self.window.scale_factor();
+		let window: Dimensions = self.window.inner_size().into();
+		if window.width <= 0.0 || window.height <= 0.0 { return; } // Minimized, keep the last size until the window comes back
+		let mouse = self.state.mouse.map(|mouse| self.state.to_physical(mouse));
+		if let Some(target) = self.state.virtual_resolution {
+			let (resolution, present_rect) = self.state.scaling.layout(window, target);
+			self.state.resolution = resolution;
+			self.state.present_rect = present_rect;
+		} else {
+			let ratio = match self.state.coordinates {
+				CoordinateSpace::Physical => 1.0,
+				CoordinateSpace::Logical => self.state.scale_factor,
+			}; // end let ratio
+			self.state.resolution = Dimensions::new(window.width / ratio, window.height / ratio);
+			self.state.present_rect = BoundingBox::new(0.0, 0.0, window.width, window.height);
+		} // end if let Some(target)
+		self.state.mouse = mouse.map(|mouse| self.state.from_physical(mouse));
 		self.gpu.resize();
+		self.resize_texture();
 	} // end fn resize
+	fn resize_texture(&mut self) {
+		let base = match self.state.virtual_resolution {
+			Some(_) => self.state.resolution,
+			None => self.window.inner_size().into(),
+		}; // end let base
+		if base.width <= 0.0 || base.height <= 0.0 { return; }
+		let scale = self.state.render_scale;
+		self.gpu.resize_texture((base.width * scale).ceil() as u32, (base.height * scale).ceil() as u32);
+	} // end fn resize_texture
+	// Scales the resolution vello renders at relative to the window, trading quality for performance below 1.0
+	pub fn set_render_scale(&mut self, scale: f64) {
+		self.state.render_scale = scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+		self.resize_texture();
+	} // end fn set_render_scale
+	pub fn set_coordinate_space(&mut self, coordinates: CoordinateSpace) {
+		self.state.coordinates = coordinates;
+		self.resize();
+	} // end fn set_coordinate_space
+	pub fn set_virtual_resolution(&mut self, resolution: Option<Dimensions>, scaling: ScalingMode) {
+		self.state.virtual_resolution = resolution;
+		self.state.scaling = scaling;
+		self.resize();
+	} // end fn set_virtual_resolution
+	pub fn set_nearest_filtering(&mut self, nearest: bool) {
+		self.gpu.set_filter(if nearest { wgpu::FilterMode::Nearest } else { wgpu::FilterMode::Linear });
+	} // end fn set_nearest_filtering
 	pub fn render(&mut self) {
 
-		let window_texture: wgpu::SurfaceTexture = self.gpu.surface.get_current_texture().unwrap();
+		// Nothing to draw into while minimized, resize keeps the last sizes then so the window itself is checked
+		let size = self.window.inner_size();
+		if self.window.is_minimized() == Some(true) || size.width == 0 || size.height == 0 { return; }
+		let (resolution, present_rect) = (self.state.resolution, &self.state.present_rect);
+		if resolution.width <= 0.0 || resolution.height <= 0.0 || present_rect.width <= 0.0 || present_rect.height <= 0.0 { return; }
+
+		let window_texture: wgpu::SurfaceTexture = match self.gpu.surface.get_current_texture() {
+			Ok(texture) => texture,
+			Err(wgpu::SurfaceError::Outdated | wgpu::SurfaceError::Lost) => { self.gpu.resize(); return; } // Reconfigure and skip the frame
+			Err(wgpu::SurfaceError::OutOfMemory) => { panic!("Out of memory while acquiring the surface texture"); }
+			Err(error) => { log!("Skipping frame: {}", error); return; }
+		}; // end let window_texture
 		let window_view: wgpu::TextureView = window_texture.texture.create_view(&wgpu::TextureViewDescriptor {
 			label: Some("Window View"),
 			format: Some(self.gpu.config.format),
 			..Default::default()
 		}); // end let window_view
 
+		let (width, height) = (self.gpu.texture.width(), self.gpu.texture.height());
+		let scale_x = width as f64 / self.state.resolution.width;
+		let scale_y = height as f64 / self.state.resolution.height;
+		let scene = if scale_x == 1.0 && scale_y == 1.0 && self.viewports.is_empty() { &self.scene.0 } else {
+			let transform = vello::kurbo::Affine::scale_non_uniform(scale_x, scale_y);
+			self.root.reset();
+			self.root.append(&self.scene.0, Some(transform));
+			for viewport in &self.viewports { viewport.draw(&mut self.root, transform); }
+			&self.root
+		}; // end let scene
+
+		let mut encoder = self.gpu.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+			label: Some("PostProcess Encoder"),
+		});
+
+		let compositing = self.hooks.is_compositing();
+		if compositing {
+			self.hooks.prepare_underlay(&self.gpu.device, width, height);
+			// Cleared every frame so the hook never sees last frame's composited image
+			if let Some((_, view)) = &self.hooks.underlay {
+				encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+					label: Some("Underlay Clear Pass"),
+					color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+						view,
+						resolve_target: None,
+						ops: wgpu::Operations {
+							load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+							store: wgpu::StoreOp::Store,
+						}, // end ops
+						depth_slice: None,
+					})], // end color_attachments
+					depth_stencil_attachment: None,
+					timestamp_writes: None,
+					occlusion_query_set: None,
+				}); // end begin_render_pass
+			} // end if let Some(underlay)
+		} // end if compositing
+		if let (true, Some(before), Some((_, view))) = (compositing, &mut self.hooks.before, &self.hooks.underlay) {
+			before(&mut PassContext {
+				device: &self.gpu.device,
+				queue: &self.gpu.queue,
+				encoder: &mut encoder,
+				view,
+				format: wgpu::TextureFormat::Rgba8Unorm,
+				size: (width, height),
+			}); // end before
+		} // end if let Some(before)
+
 		let _ = self.gpu.renderer.render_to_texture(
 				&self.gpu.device,
 				&self.gpu.queue,
-				&self.scene.0,
+				scene,
 				&self.gpu.texture_view,
 				&vello::RenderParams {
-					base_color: AlphaColor::BLACK,
-					width: self.gpu.config.width,
-					height: self.gpu.config.height,
+					base_color: if compositing { AlphaColor::TRANSPARENT } else { AlphaColor::BLACK },
+					width,
+					height,
 					antialiasing_method: vello::AaConfig::Msaa16,
 				} // end RenderParams
 			); // end render_to_texture
 
-		let mut encoder = self.gpu.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
-			label: Some("PostProcess Encoder"),
-		});
+		// Blend the vello output over the underlay, which then stands in for the vello output
+		let mut input = None;
+		if let (true, Some((_, underlay))) = (compositing, &self.hooks.underlay) {
+			let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+				label: Some("Composite Pass"),
+				color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+					view: underlay,
+					resolve_target: None,
+					ops: wgpu::Operations {
+						load: wgpu::LoadOp::Load,
+						store: wgpu::StoreOp::Store,
+					}, // end ops
+					depth_slice: None,
+				})], // end color_attachments
+				depth_stencil_attachment: None,
+				timestamp_writes: None,
+				occlusion_query_set: None,
+			}); // end let mut pass
+			pass.set_pipeline(&self.gpu.composite_pipeline);
+			pass.set_bind_group(0, &self.gpu.bind_group, &[]);
+			pass.draw(0..3, 0..1); // Fullscreen triangle
+			input = Some(underlay);
+		} // end if compositing
+
+		let output = self.post.render(&self.gpu, &mut encoder, input.unwrap_or(&self.gpu.texture_view), &self.state).or(input);
+		let output_bind_group = output.map(|view| self.gpu.create_bind_group(view));
 
 		{	let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
 				label: Some("PostProcess Pass"),
@@ -65,11 +257,24 @@ impl Context {
 				occlusion_query_set: None,
 			}); // end let mut pass
 			
+			let rect = &self.state.present_rect;
+			pass.set_viewport(rect.x as f32, rect.y as f32, rect.width as f32, rect.height as f32, 0.0, 1.0);
 			pass.set_pipeline(&self.gpu.pipeline);
-			pass.set_bind_group(0, &self.gpu.bind_group, &[]);
+			pass.set_bind_group(0, output_bind_group.as_ref().unwrap_or(&self.gpu.bind_group), &[]);
 			pass.draw(0..3, 0..1); // Fullscreen triangle
 		} // end let mut pass
 
+		if let Some(after) = &mut self.hooks.after {
+			after(&mut PassContext {
+				device: &self.gpu.device,
+				queue: &self.gpu.queue,
+				encoder: &mut encoder,
+				view: &window_view,
+				format: self.gpu.config.format,
+				size: (self.gpu.config.width, self.gpu.config.height),
+			}); // end after
+		} // end if let Some(after)
+
 		self.gpu.queue.submit(Some(encoder.finish()));
 		window_texture.present();
 
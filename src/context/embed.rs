@@ -0,0 +1,206 @@
+
+// SceneRenderer renders a Scene with a device owned by another wgpu application, without any window
+
+use std::collections::HashMap;
+
+use vello::{peniko::color::AlphaColor, wgpu::{self, PipelineCompilationOptions, TextureFormat}};
+
+use crate::prelude::*;
+
+pub struct SceneRenderer {
+	pub device: wgpu::Device,
+	pub queue: wgpu::Queue,
+	pub renderer: vello::Renderer,
+	pub texture: Option<(wgpu::Texture, wgpu::TextureView)>,
+	pub compositing: Compositing,
+	pub base_color: Color,
+	pub antialiasing: vello::AaConfig,
+	bind_group_layout: wgpu::BindGroupLayout,
+	pipeline_layout: wgpu::PipelineLayout,
+	shader: wgpu::ShaderModule,
+	sampler: wgpu::Sampler,
+	pipelines: HashMap<(TextureFormat, Compositing), wgpu::RenderPipeline>,
+} // end struct SceneRenderer
+
+impl SceneRenderer {
+
+	pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+		log!("Loading Vello renderer... This might take a while.");
+		let renderer = vello::Renderer::new(
+			device,
+			vello::RendererOptions {
+				use_cpu: false,
+				antialiasing_support: vello::AaSupport::all(),
+				num_init_threads: None,
+				pipeline_cache: None,
+			}, // end RendererOptions
+		).unwrap(); // end let renderer
+		log!("Vello renderer loaded!");
+		let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+			label: Some("SceneRenderer Sampler"),
+			mag_filter: wgpu::FilterMode::Nearest,
+			min_filter: wgpu::FilterMode::Nearest,
+			..Default::default()
+		}); // end let sampler
+		let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+			label: Some("SceneRenderer Bind Group Layout"),
+			entries: &[
+				wgpu::BindGroupLayoutEntry {
+					binding: 0,
+					visibility: wgpu::ShaderStages::FRAGMENT,
+					ty: wgpu::BindingType::Texture {
+						sample_type: wgpu::TextureSampleType::Float { filterable: true },
+						view_dimension: wgpu::TextureViewDimension::D2,
+						multisampled: false,
+					}, count: None,
+				}, // end wgpu::BindGroupLayoutEntry
+				wgpu::BindGroupLayoutEntry {
+					binding: 1,
+					visibility: wgpu::ShaderStages::FRAGMENT,
+					ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+					count: None,
+				}, // end wgpu::BindGroupLayoutEntry
+			], // end entries
+		}); // end let bind_group_layout
+		let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+			label: Some("SceneRenderer Pipeline Layout"),
+			bind_group_layouts: &[&bind_group_layout],
+			push_constant_ranges: &[],
+		}); // end let pipeline_layout
+		let shader = device.create_shader_module(wgpu::include_wgsl!("../../shaders/swizzle.wgsl"));
+		return Self {
+			device: device.clone(),
+			queue: queue.clone(),
+			renderer,
+			texture: None,
+			compositing: Compositing::Over,
+			base_color: [0, 0, 0, 255].into(),
+			antialiasing: vello::AaConfig::Msaa16,
+			bind_group_layout,
+			pipeline_layout,
+			shader,
+			sampler,
+			pipelines: HashMap::new(),
+		}; // end return Self
+	} // end fn new
+
+	// Renders the scene into a 2D texture view of the given format and size, which is the size of the viewed mip level
+	// sRGB formats are rejected, vello output is already sRGB encoded and the view would encode it a second time
+	pub fn render(&mut self, scene: &Scene, view: &wgpu::TextureView, format: TextureFormat, size: (u32, u32)) -> Result<(), String> {
+		if format.is_srgb() { return Err(format!("SceneRenderer cannot render into the sRGB format {:?}, use a non-sRGB view of the texture", format)); }
+		let (width, height) = size;
+		if width == 0 || height == 0 { return Err(format!("SceneRenderer cannot render into an empty {}x{} target", width, height)); }
+
+		if self.texture.as_ref().map_or(true, |(texture, _)| texture.width() != width || texture.height() != height) {
+			let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+				label: Some("SceneRenderer Texture"),
+				size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+				mip_level_count: 1,
+				sample_count: 1,
+				dimension: wgpu::TextureDimension::D2,
+				format: TextureFormat::Rgba8Unorm,
+				usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::STORAGE_BINDING,
+				view_formats: &[],
+			}); // end let texture
+			let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+			self.texture = Some((texture, texture_view));
+		} // end if texture needs resizing
+		let texture_view = &self.texture.as_ref().unwrap().1;
+
+		let Color { r, g, b, a } = &self.base_color;
+		self.renderer.render_to_texture(
+			&self.device,
+			&self.queue,
+			&scene.0,
+			texture_view,
+			&vello::RenderParams {
+				base_color: match self.compositing {
+					Compositing::Over => AlphaColor::TRANSPARENT,
+					Compositing::Clear => AlphaColor::from_rgba8(*r, *g, *b, *a),
+				}, // end base_color
+				width,
+				height,
+				antialiasing_method: self.antialiasing,
+			} // end RenderParams
+		).map_err(|error| error.to_string())?; // end render_to_texture
+
+		let key = (format, self.compositing);
+		if !self.pipelines.contains_key(&key) {
+			let pipeline = self.device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+				label: Some("SceneRenderer Pipeline"),
+				layout: Some(&self.pipeline_layout),
+				vertex: wgpu::VertexState {
+					module: &self.shader,
+					entry_point: Some("vs_main"),
+					buffers: &[],
+					compilation_options: PipelineCompilationOptions::default(),
+				}, // end vertex: wgpu::VertexState
+				fragment: Some(wgpu::FragmentState {
+					module: &self.shader,
+					entry_point: Some("fs_main"),
+					targets: &[Some(wgpu::ColorTargetState {
+						format,
+						blend: Some(match self.compositing {
+							Compositing::Over => wgpu::BlendState::ALPHA_BLENDING,
+							Compositing::Clear => wgpu::BlendState::REPLACE,
+						}), // end blend
+						write_mask: wgpu::ColorWrites::ALL,
+					})], // end targets
+					compilation_options: PipelineCompilationOptions::default(),
+				}), // end Some(wgpu::FragmentState)
+				primitive: Default::default(),
+				depth_stencil: None,
+				multisample: Default::default(),
+				multiview: None,
+				cache: None,
+			}); // end let pipeline
+			self.pipelines.insert(key, pipeline);
+		} // end if pipeline is missing
+
+		let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+			label: Some("SceneRenderer Bind Group"),
+			layout: &self.bind_group_layout,
+			entries: &[
+				wgpu::BindGroupEntry {
+					binding: 0,
+					resource: wgpu::BindingResource::TextureView(texture_view),
+				}, // end wgpu::BindGroupEntry
+				wgpu::BindGroupEntry {
+					binding: 1,
+					resource: wgpu::BindingResource::Sampler(&self.sampler),
+				}, // end wgpu::BindGroupEntry
+			], // end entries
+		}); // end let bind_group
+
+		let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+			label: Some("SceneRenderer Encoder"),
+		});
+		{	let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+				label: Some("SceneRenderer Pass"),
+				color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+					view,
+					resolve_target: None,
+					ops: wgpu::Operations {
+						load: wgpu::LoadOp::Load,
+						store: wgpu::StoreOp::Store,
+					}, // end ops
+					depth_slice: None,
+				})], // end color_attachments
+				depth_stencil_attachment: None,
+				timestamp_writes: None,
+				occlusion_query_set: None,
+			}); // end let mut pass
+			pass.set_pipeline(&self.pipelines[&key]);
+			pass.set_bind_group(0, &bind_group, &[]);
+			pass.draw(0..3, 0..1); // Fullscreen triangle
+		} // end let mut pass
+		self.queue.submit(Some(encoder.finish()));
+		return Ok(());
+	} // end fn render
+
+	// Registers an external texture so it can be drawn as an Image in scenes rendered by this renderer
+	pub fn register_texture(&mut self, texture: wgpu::Texture) -> Image {
+		return Image { image: self.renderer.register_texture(texture) };
+	} // end fn register_texture
+
+} // end impl SceneRenderer
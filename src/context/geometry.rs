@@ -0,0 +1,104 @@
+
+// WindowGeometry stores the size, position and state of a window between runs
+
+use winit::{dpi::{PhysicalPosition, PhysicalSize}, event_loop::ActiveEventLoop, window::Window};
+
+use crate::prelude::*;
+
+// Minimum amount of the window (in physical pixels) that must overlap a monitor to be considered visible
+const MIN_VISIBLE: i64 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowGeometry {
+	pub size: Dimensions,
+	pub position: Option<(i32, i32)>,
+	pub maximized: bool,
+	pub fullscreen: bool,
+} // end struct WindowGeometry
+
+impl WindowGeometry {
+
+	pub fn new(size: impl Into<Dimensions>) -> Self {
+		return Self { size: size.into(), position: None, maximized: false, fullscreen: false };
+	} // end fn new
+
+	// Updates the stored geometry from the window, keeping the last windowed size and position
+	// while the window is maximized or fullscreen so that un-maximizing restores correctly
+	pub fn track(&mut self, window: &Window) {
+		self.maximized = window.is_maximized();
+		self.fullscreen = window.fullscreen().is_some();
+		if self.maximized || self.fullscreen || window.is_minimized() == Some(true) { return; }
+		self.size = window.inner_size().into();
+		if let Ok(position) = window.outer_position() { self.position = Some((position.x, position.y)); }
+	} // end fn track
+
+	// Drops the position if the window would not be visible on any connected monitor,
+	// and shrinks the size to fit the largest monitor
+	pub fn validate(&mut self, event_loop: &ActiveEventLoop) {
+		let monitors: Vec<_> = event_loop.available_monitors().collect();
+		if monitors.is_empty() { return; }
+		let max_width = monitors.iter().map(|m| m.size().width).max().unwrap_or(0) as f64;
+		let max_height = monitors.iter().map(|m| m.size().height).max().unwrap_or(0) as f64;
+		self.size.width = self.size.width.min(max_width).max(40.0);
+		self.size.height = self.size.height.min(max_height).max(40.0);
+		if let Some((x, y)) = self.position {
+			let (x0, y0) = (x as i64, y as i64);
+			let (x1, y1) = (x0 + self.size.width as i64, y0 + self.size.height as i64);
+			let visible = monitors.iter().any(|monitor| {
+				let PhysicalPosition { x: mx, y: my } = monitor.position();
+				let PhysicalSize { width, height } = monitor.size();
+				let (mx0, my0) = (mx as i64, my as i64);
+				let (mx1, my1) = (mx0 + width as i64, my0 + height as i64);
+				let overlap_x = x1.min(mx1) - x0.max(mx0);
+				let overlap_y = y1.min(my1) - y0.max(my0);
+				return overlap_x >= MIN_VISIBLE && overlap_y >= MIN_VISIBLE;
+			}); // end let visible
+			if !visible { self.position = None; }
+		} // end if let Some(position)
+	} // end fn validate
+
+	pub fn load(key: &str) -> Option<Self> {
+		let path = Self::path(key)?;
+		let contents = std::fs::read_to_string(path).ok()?;
+		let mut width = None; let mut height = None;
+		let mut x = None; let mut y = None;
+		let mut maximized = false; let mut fullscreen = false;
+		for line in contents.lines() {
+			let Some((name, value)) = line.split_once('=') else { continue; };
+			let value = value.trim();
+			match name.trim() {
+				"width" => { width = value.parse::<f64>().ok(); }
+				"height" => { height = value.parse::<f64>().ok(); }
+				"x" => { x = value.parse::<i32>().ok(); }
+				"y" => { y = value.parse::<i32>().ok(); }
+				"maximized" => { maximized = value == "true"; }
+				"fullscreen" => { fullscreen = value == "true"; }
+				_ => {}
+			} // end match name
+		} // end for line
+		let size = Dimensions::new(width?, height?);
+		if !(size.width > 0.0 && size.height > 0.0) { return None; }
+		let position = match (x, y) { (Some(x), Some(y)) => Some((x, y)), _ => None };
+		return Some(Self { size, position, maximized, fullscreen });
+	} // end fn load
+
+	pub fn save(&self, key: &str) {
+		let Some(path) = Self::path(key) else { return; };
+		let mut contents = format!("width={}\nheight={}\n", self.size.width, self.size.height);
+		if let Some((x, y)) = self.position { contents += &format!("x={}\ny={}\n", x, y); }
+		contents += &format!("maximized={}\nfullscreen={}\n", self.maximized, self.fullscreen);
+		if let Some(parent) = path.parent() { let _ = std::fs::create_dir_all(parent); }
+		if let Err(err) = std::fs::write(&path, contents) { log!("Could not save window geometry: {}", err); }
+	} // end fn save
+
+	#[cfg(not(target_arch = "wasm32"))]
+	fn path(key: &str) -> Option<std::path::PathBuf> {
+		let file: String = key.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect();
+		return Some(dirs::config_dir()?.join("purple").join(format!("{}.geometry", file)));
+	} // end fn path
+
+	// The window size is dictated by the canvas on the web, so nothing is persisted
+	#[cfg(target_arch = "wasm32")]
+	fn path(_key: &str) -> Option<std::path::PathBuf> { return None; }
+
+} // end impl WindowGeometry
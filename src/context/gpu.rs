@@ -18,6 +18,7 @@ pub struct Gpu {
 	pub bind_group			: wgpu::BindGroup				,	
 	pub pipeline_layout		: wgpu::PipelineLayout			,
 	pub pipeline			: wgpu::RenderPipeline			,
+	pub composite_pipeline	: wgpu::RenderPipeline			,
 	pub swizzle_shader		: wgpu::ShaderModule			,
 	pub sampler				: wgpu::Sampler					,
 } // end struct Gpu
@@ -163,6 +164,31 @@ impl Gpu {
 			multiview: None,
 			cache: None,
 		}); // end let pipeline
+		let composite_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+			label: Some("Composite Pipeline"),
+			layout: Some(&pipeline_layout),
+			vertex: wgpu::VertexState {
+				module: &swizzle_shader,
+				entry_point: Some("vs_main"),
+				buffers: &[],
+				compilation_options: PipelineCompilationOptions::default(),
+			}, // end vertex: wgpu::VertexState
+			fragment: Some(wgpu::FragmentState {
+				module: &swizzle_shader,
+				entry_point: Some("fs_main"),
+				targets: &[Some(wgpu::ColorTargetState {
+					format: TextureFormat::Rgba8Unorm,
+					blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+					write_mask: wgpu::ColorWrites::ALL,
+				})], // end targets
+				compilation_options: PipelineCompilationOptions::default(),
+			}), // end Some(wgpu::FragmentState)
+			primitive: Default::default(),
+			depth_stencil: None,
+			multisample: Default::default(),
+			multiview: None,
+			cache: None,
+		}); // end let composite_pipeline
 
 		return Self {
 			
@@ -179,6 +205,7 @@ impl Gpu {
 			bind_group,
 			pipeline_layout,
 			pipeline,
+			composite_pipeline,
 			sampler,
 			swizzle_shader,
 
@@ -191,11 +218,18 @@ impl Gpu {
 		self.config.width = (resolution.width as u32).max(1);
 		self.config.height = (resolution.height as u32).max(1);
 		self.surface.configure(&self.device, &self.config);
+	} // end fn resize
+
+	// Resizes the texture vello renders into, which does not have to match the surface
+	pub fn resize_texture(&mut self, width: u32, height: u32) {
+		let max = self.device.limits().max_texture_dimension_2d;
+		let (width, height) = (width.clamp(1, max), height.clamp(1, max));
+		if self.texture.width() == width && self.texture.height() == height { return; }
 		self.texture = self.device.create_texture(&wgpu::TextureDescriptor {
 			label: Some("Buffer Texture"),
 			size: wgpu::Extent3d {
-				width: resolution.width.max(1),
-				height: resolution.height.max(1),
+				width,
+				height,
 				depth_or_array_layers: 1,
 			},
 			mip_level_count: 1,
@@ -209,20 +243,44 @@ impl Gpu {
 			label: Some("Buffer Texture View"),
 			..Default::default()
 		}); // end let buffer_texture_view
-		self.bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+		self.rebind();
+	} // end fn resize_texture
+
+	// Sets the filter used when the texture is scaled onto the surface
+	pub fn set_filter(&mut self, filter: wgpu::FilterMode) {
+		self.sampler = self.device.create_sampler(&wgpu::SamplerDescriptor {
+			label: Some("PostProcess Sampler"),
+			address_mode_u: wgpu::AddressMode::ClampToEdge,
+			address_mode_v: wgpu::AddressMode::ClampToEdge,
+			address_mode_w: wgpu::AddressMode::ClampToEdge,
+			mag_filter: filter,
+			min_filter: filter,
+			mipmap_filter: wgpu::FilterMode::Nearest,
+			..Default::default()
+		}); // end let sampler
+		self.rebind();
+	} // end fn set_filter
+
+	fn rebind(&mut self) {
+		self.bind_group = self.create_bind_group(&self.texture_view);
+	} // end fn rebind
+
+	// Creates a bind group for presenting any Rgba8Unorm texture view with the swizzle pipeline
+	pub fn create_bind_group(&self, view: &wgpu::TextureView) -> wgpu::BindGroup {
+		return self.device.create_bind_group(&wgpu::BindGroupDescriptor {
 			label: Some("RGBA to BGRA Bind Group"),
 			layout: &self.bind_group_layout,
 			entries: &[
 				wgpu::BindGroupEntry {
 					binding: 0,
-					resource: wgpu::BindingResource::TextureView(&self.texture_view), // Rgba8Unorm texture view
+					resource: wgpu::BindingResource::TextureView(view), // Rgba8Unorm texture view
 				}, // end wgpu::BindGroupEntry
 				wgpu::BindGroupEntry {
 					binding: 1,
 					resource: wgpu::BindingResource::Sampler(&self.sampler),
 				}, // end wgpu::BindGroupEntry
 			], // end entries
-		}); // end let bind_group
-	} // end fn resize
+		}); // end return
+	} // end fn create_bind_group
 } // end impl Gpu
 
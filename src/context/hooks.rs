@@ -0,0 +1,55 @@
+
+// RenderHooks let users record their own wgpu passes underneath and on top of the vello output
+
+use vello::wgpu;
+
+// Everything needed to record passes into a render target
+pub struct PassContext<'a> {
+	pub device: &'a wgpu::Device,
+	pub queue: &'a wgpu::Queue,
+	pub encoder: &'a mut wgpu::CommandEncoder,
+	pub view: &'a wgpu::TextureView,
+	pub format: wgpu::TextureFormat,
+	pub size: (u32, u32),
+} // end struct PassContext
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Compositing {
+	#[default] Over, // Vello renders with a transparent background and is blended over the underlay
+	Clear, // Vello clears to black, the before hook is skipped since nothing of it would show
+} // end enum Compositing
+
+pub type RenderHook = Box<dyn FnMut(&mut PassContext)>;
+
+pub struct RenderHooks {
+	pub before: Option<RenderHook>, // Rendered into an Rgba8Unorm texture the size of the vello output, cleared to transparent each frame, only with Compositing::Over
+	pub after: Option<RenderHook>, // Rendered into the surface after post-processing
+	pub compositing: Compositing,
+	pub(crate) underlay: Option<(wgpu::Texture, wgpu::TextureView)>,
+} // end struct RenderHooks
+
+impl RenderHooks {
+	pub fn new() -> Self { return Self { before: None, after: None, compositing: Compositing::default(), underlay: None }; }
+	pub fn set_before(&mut self, hook: impl FnMut(&mut PassContext) + 'static) { self.before = Some(Box::new(hook)); }
+	pub fn set_after(&mut self, hook: impl FnMut(&mut PassContext) + 'static) { self.after = Some(Box::new(hook)); }
+	pub fn clear(&mut self) { self.before = None; self.after = None; }
+	pub fn is_compositing(&self) -> bool { return self.before.is_some() && self.compositing == Compositing::Over; }
+
+	// Creates the underlay texture, or recreates it if the vello output changed size
+	pub(crate) fn prepare_underlay(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+		if self.underlay.as_ref().map_or(true, |(texture, _)| texture.width() != width || texture.height() != height) {
+			let texture = device.create_texture(&wgpu::TextureDescriptor {
+				label: Some("Underlay Texture"),
+				size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+				mip_level_count: 1,
+				sample_count: 1,
+				dimension: wgpu::TextureDimension::D2,
+				format: wgpu::TextureFormat::Rgba8Unorm,
+				usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+				view_formats: &[],
+			}); // end let texture
+			let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+			self.underlay = Some((texture, view));
+		} // end if underlay needs resizing
+	} // end fn prepare_underlay
+} // end impl RenderHooks
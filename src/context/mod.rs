@@ -1,4 +1,10 @@
 pub mod context; pub use crate::context::context::*;
 pub mod gpu; pub use crate::context::gpu::*;
 pub mod state; pub use crate::context::state::*;
-pub mod font; pub use crate::context::font::*;
\ No newline at end of file
+pub mod font; pub use crate::context::font::*;
+pub mod geometry; pub use crate::context::geometry::*;
+pub mod scaling; pub use crate::context::scaling::*;
+pub mod post; pub use crate::context::post::*;
+pub mod hooks; pub use crate::context::hooks::*;
+pub mod embed; pub use crate::context::embed::*;
+pub mod viewport; pub use crate::context::viewport::*;
\ No newline at end of file
@@ -0,0 +1,331 @@
+
+// PostPass is a user fragment shader run over the output of vello before it reaches the surface
+
+use std::{path::PathBuf, time::SystemTime};
+
+use vello::wgpu::{self, PipelineCompilationOptions, TextureFormat};
+
+use crate::prelude::*;
+
+pub const POST_HEADER: &str = include_str!("../../shaders/post.wgsl");
+pub const POST_PARAMS: usize = 16;
+const POST_FORMAT: TextureFormat = TextureFormat::Rgba8Unorm;
+const UNIFORM_SIZE: u64 = 16 + 4 * POST_PARAMS as u64;
+const RELOAD_INTERVAL: f64 = 0.25; // Seconds between checks for modified shader files
+
+pub struct PostPass {
+	pub label: String,
+	pub source: String,
+	pub params: [f32; POST_PARAMS],
+	pub enabled: bool,
+	pub textures: Vec<wgpu::TextureView>,
+	pub pipeline: wgpu::RenderPipeline,
+	pub bind_group_layout: wgpu::BindGroupLayout,
+	pub texture_layout: Option<wgpu::BindGroupLayout>,
+	pub texture_bind_group: Option<wgpu::BindGroup>,
+	pub uniform_buffer: wgpu::Buffer,
+	pub path: Option<PathBuf>,
+	pub error: Option<String>,
+	modified: Option<SystemTime>,
+} // end struct PostPass
+
+impl PostPass {
+
+	// Shader errors are returned natively, on wasm they are only logged to the browser console
+	pub fn new(gpu: &Gpu, label: impl Into<String>, source: impl Into<String>) -> Result<Self, String> {
+		return Self::with_textures(gpu, label, source, Vec::new());
+	} // end fn new
+
+	// Extra textures are bound in order at @group(1) @binding(0..n) and sampled with input_sampler
+	pub fn with_textures(gpu: &Gpu, label: impl Into<String>, source: impl Into<String>, textures: Vec<wgpu::TextureView>) -> Result<Self, String> {
+		let label = label.into(); let source = source.into();
+		let bind_group_layout = gpu.device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+			label: Some("PostPass Bind Group Layout"),
+			entries: &[
+				wgpu::BindGroupLayoutEntry {
+					binding: 0,
+					visibility: wgpu::ShaderStages::FRAGMENT,
+					ty: wgpu::BindingType::Texture {
+						sample_type: wgpu::TextureSampleType::Float { filterable: true },
+						view_dimension: wgpu::TextureViewDimension::D2,
+						multisampled: false,
+					}, count: None,
+				}, // end wgpu::BindGroupLayoutEntry
+				wgpu::BindGroupLayoutEntry {
+					binding: 1,
+					visibility: wgpu::ShaderStages::FRAGMENT,
+					ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+					count: None,
+				}, // end wgpu::BindGroupLayoutEntry
+				wgpu::BindGroupLayoutEntry {
+					binding: 2,
+					visibility: wgpu::ShaderStages::FRAGMENT,
+					ty: wgpu::BindingType::Buffer {
+						ty: wgpu::BufferBindingType::Uniform,
+						has_dynamic_offset: false,
+						min_binding_size: wgpu::BufferSize::new(UNIFORM_SIZE),
+					}, count: None,
+				}, // end wgpu::BindGroupLayoutEntry
+			], // end entries
+		}); // end let bind_group_layout
+
+		let (texture_layout, texture_bind_group) = if textures.is_empty() { (None, None) } else {
+			let layout_entries: Vec<wgpu::BindGroupLayoutEntry> = (0..textures.len()).map(|i| wgpu::BindGroupLayoutEntry {
+				binding: i as u32,
+				visibility: wgpu::ShaderStages::FRAGMENT,
+				ty: wgpu::BindingType::Texture {
+					sample_type: wgpu::TextureSampleType::Float { filterable: true },
+					view_dimension: wgpu::TextureViewDimension::D2,
+					multisampled: false,
+				}, count: None,
+			}).collect(); // end let layout_entries
+			let texture_layout = gpu.device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+				label: Some("PostPass Texture Bind Group Layout"),
+				entries: &layout_entries,
+			}); // end let texture_layout
+			let entries: Vec<wgpu::BindGroupEntry> = textures.iter().enumerate().map(|(i, view)| wgpu::BindGroupEntry {
+				binding: i as u32,
+				resource: wgpu::BindingResource::TextureView(view),
+			}).collect(); // end let entries
+			let texture_bind_group = gpu.device.create_bind_group(&wgpu::BindGroupDescriptor {
+				label: Some("PostPass Texture Bind Group"),
+				layout: &texture_layout,
+				entries: &entries,
+			}); // end let texture_bind_group
+			(Some(texture_layout), Some(texture_bind_group))
+		}; // end let (texture_layout, texture_bind_group)
+
+		let pipeline = Self::compile(gpu, &label, &source, &bind_group_layout, texture_layout.as_ref())?;
+		let uniform_buffer = gpu.device.create_buffer(&wgpu::BufferDescriptor {
+			label: Some("PostPass Uniform Buffer"),
+			size: UNIFORM_SIZE,
+			usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+			mapped_at_creation: false,
+		}); // end let uniform_buffer
+
+		return Ok(Self {
+			label,
+			source,
+			params: [0.0; POST_PARAMS],
+			enabled: true,
+			textures,
+			pipeline,
+			bind_group_layout,
+			texture_layout,
+			texture_bind_group,
+			uniform_buffer,
+			path: None,
+			error: None,
+			modified: None,
+		}); // end return Ok
+	} // end fn with_textures
+
+	pub fn from_file(gpu: &Gpu, label: impl Into<String>, path: impl Into<PathBuf>) -> Result<Self, String> {
+		let path = path.into();
+		let source = std::fs::read_to_string(&path).map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+		return Ok(Self::new(gpu, label, source)?.watch(path));
+	} // end fn from_file
+
+	// Ties the pass to a shader file so it is recompiled when the file changes and hot reloading is enabled
+	pub fn watch(mut self, path: impl Into<PathBuf>) -> Self {
+		let path = path.into();
+		self.modified = std::fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+		self.path = Some(path);
+		return self;
+	} // end fn watch
+
+	// Recompiles the pass if its file has changed, keeping the previous pipeline if compilation fails
+	pub fn reload(&mut self, gpu: &Gpu) -> Result<bool, String> {
+		let Some(path) = &self.path else { return Ok(false); };
+		let modified = std::fs::metadata(path).and_then(|meta| meta.modified()).ok();
+		if modified.is_none() || modified == self.modified { return Ok(false); }
+		self.modified = modified;
+		let result = std::fs::read_to_string(path)
+		.map_err(|err| format!("Could not read {}: {}", path.display(), err))
+		.and_then(|source| {
+			let pipeline = Self::compile(gpu, &self.label, &source, &self.bind_group_layout, self.texture_layout.as_ref())?;
+			return Ok((source, pipeline));
+		}); // end let result
+		match result {
+			Ok((source, pipeline)) => {
+				self.source = source;
+				self.pipeline = pipeline;
+				self.error = None;
+				return Ok(true);
+			} // end Ok
+			Err(error) => {
+				self.error = Some(error.clone());
+				return Err(error);
+			} // end Err
+		} // end match result
+	} // end fn reload
+
+	// Builds the pipeline for a fragment shader, returning the validation error instead of panicking on native targets
+	fn compile(gpu: &Gpu, label: &str, source: &str, bind_group_layout: &wgpu::BindGroupLayout, texture_layout: Option<&wgpu::BindGroupLayout>) -> Result<wgpu::RenderPipeline, String> {
+		// The error scope can only be awaited natively, on wasm errors go to the uncaptured error handler and the console
+		#[cfg(not(target_arch = "wasm32"))]
+		gpu.device.push_error_scope(wgpu::ErrorFilter::Validation);
+		let shader = gpu.device.create_shader_module(wgpu::ShaderModuleDescriptor {
+			label: Some(label),
+			source: wgpu::ShaderSource::Wgsl(format!("{}\n{}", POST_HEADER, source).into()),
+		}); // end let shader
+		let bind_group_layouts: Vec<&wgpu::BindGroupLayout> = std::iter::once(bind_group_layout).chain(texture_layout).collect();
+		let pipeline_layout = gpu.device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+			label: Some("PostPass Pipeline Layout"),
+			bind_group_layouts: &bind_group_layouts,
+			push_constant_ranges: &[],
+		}); // end let pipeline_layout
+		let pipeline = gpu.device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+			label: Some(label),
+			layout: Some(&pipeline_layout),
+			vertex: wgpu::VertexState {
+				module: &shader,
+				entry_point: Some("vs_main"),
+				buffers: &[],
+				compilation_options: PipelineCompilationOptions::default(),
+			}, // end vertex: wgpu::VertexState
+			fragment: Some(wgpu::FragmentState {
+				module: &shader,
+				entry_point: Some("fs_main"),
+				targets: &[Some(wgpu::ColorTargetState {
+					format: POST_FORMAT,
+					blend: Some(wgpu::BlendState::REPLACE),
+					write_mask: wgpu::ColorWrites::ALL,
+				})], // end targets
+				compilation_options: PipelineCompilationOptions::default(),
+			}), // end Some(wgpu::FragmentState)
+			primitive: Default::default(),
+			depth_stencil: None,
+			multisample: Default::default(),
+			multiview: None,
+			cache: None,
+		}); // end let pipeline
+		#[cfg(not(target_arch = "wasm32"))]
+		if let Some(error) = async_std::task::block_on(gpu.device.pop_error_scope()) {
+			return Err(format!("Could not compile post pass \"{}\": {}", label, error));
+		} // end if let Some(error)
+		return Ok(pipeline);
+	} // end fn compile
+
+	pub fn render(&self, gpu: &Gpu, encoder: &mut wgpu::CommandEncoder, input: &wgpu::TextureView, output: &wgpu::TextureView, resolution: Dimensions, state: &State) {
+		let mut uniforms: Vec<u8> = Vec::with_capacity(UNIFORM_SIZE as usize);
+		for value in [resolution.width as f32, resolution.height as f32, state.time as f32, state.delta as f32].iter().chain(self.params.iter()) {
+			uniforms.extend_from_slice(&value.to_ne_bytes());
+		} // end for value
+		gpu.queue.write_buffer(&self.uniform_buffer, 0, &uniforms);
+
+		let bind_group = gpu.device.create_bind_group(&wgpu::BindGroupDescriptor {
+			label: Some("PostPass Bind Group"),
+			layout: &self.bind_group_layout,
+			entries: &[
+				wgpu::BindGroupEntry {
+					binding: 0,
+					resource: wgpu::BindingResource::TextureView(input),
+				}, // end wgpu::BindGroupEntry
+				wgpu::BindGroupEntry {
+					binding: 1,
+					resource: wgpu::BindingResource::Sampler(&gpu.sampler),
+				}, // end wgpu::BindGroupEntry
+				wgpu::BindGroupEntry {
+					binding: 2,
+					resource: self.uniform_buffer.as_entire_binding(),
+				}, // end wgpu::BindGroupEntry
+			], // end entries
+		}); // end let bind_group
+
+		let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+			label: Some(&self.label),
+			color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+				view: output,
+				resolve_target: None,
+				ops: wgpu::Operations {
+					load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+					store: wgpu::StoreOp::Store,
+				}, // end ops
+				depth_slice: None,
+			})], // end color_attachments
+			depth_stencil_attachment: None,
+			timestamp_writes: None,
+			occlusion_query_set: None,
+		}); // end let mut pass
+		pass.set_pipeline(&self.pipeline);
+		pass.set_bind_group(0, &bind_group, &[]);
+		if let Some(texture_bind_group) = &self.texture_bind_group { pass.set_bind_group(1, texture_bind_group, &[]); }
+		pass.draw(0..3, 0..1); // Fullscreen triangle
+	} // end fn render
+
+} // end impl PostPass
+
+// PostChain runs its enabled passes in order, ping-ponging between two textures the size of the vello output
+pub struct PostChain {
+	pub passes: Vec<PostPass>,
+	pub hot_reload: bool,
+	targets: Vec<(wgpu::Texture, wgpu::TextureView)>,
+	last_check: f64,
+} // end struct PostChain
+
+impl PostChain {
+
+	pub fn new() -> Self { return Self { passes: Vec::new(), hot_reload: false, targets: Vec::new(), last_check: 0.0 }; }
+
+	// Checks every watched pass for changes, logging compile errors instead of panicking
+	pub fn reload(&mut self, gpu: &Gpu) {
+		for pass in &mut self.passes {
+			match pass.reload(gpu) {
+				Ok(true) => { log!("Reloaded post pass \"{}\"", pass.label); }
+				Ok(false) => {}
+				Err(error) => { log!("{}", error); }
+			} // end match pass.reload
+		} // end for pass
+	} // end fn reload
+
+	pub fn push(&mut self, pass: PostPass) { self.passes.push(pass); }
+
+	pub fn get_mut(&mut self, label: &str) -> Option<&mut PostPass> {
+		return self.passes.iter_mut().find(|pass| pass.label == label);
+	} // end fn get_mut
+
+	pub fn remove(&mut self, label: &str) -> Option<PostPass> {
+		let index = self.passes.iter().position(|pass| pass.label == label)?;
+		return Some(self.passes.remove(index));
+	} // end fn remove
+
+	pub fn is_active(&self) -> bool { return self.passes.iter().any(|pass| pass.enabled); }
+
+	// Records every enabled pass, returning the view holding the final result if any pass ran
+	pub fn render<'a>(&'a mut self, gpu: &Gpu, encoder: &mut wgpu::CommandEncoder, input: &'a wgpu::TextureView, state: &State) -> Option<&'a wgpu::TextureView> {
+		if self.hot_reload && (state.time - self.last_check).abs() >= RELOAD_INTERVAL {
+			self.last_check = state.time;
+			self.reload(gpu);
+		} // end if self.hot_reload
+		if !self.is_active() { return None; }
+		let (width, height) = (gpu.texture.width(), gpu.texture.height());
+		if self.targets.first().map_or(true, |(texture, _)| texture.width() != width || texture.height() != height) {
+			self.targets = (0..2).map(|_| {
+				let texture = gpu.device.create_texture(&wgpu::TextureDescriptor {
+					label: Some("PostProcess Texture"),
+					size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+					mip_level_count: 1,
+					sample_count: 1,
+					dimension: wgpu::TextureDimension::D2,
+					format: POST_FORMAT,
+					usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+					view_formats: &[],
+				}); // end let texture
+				let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+				(texture, view)
+			}).collect(); // end let targets
+		} // end if targets need resizing
+		let resolution = Dimensions::new(width as f64, height as f64);
+		let mut current = 0;
+		let mut input = input;
+		for pass in self.passes.iter().filter(|pass| pass.enabled) {
+			let output = &self.targets[current].1;
+			pass.render(gpu, encoder, input, output, resolution, state);
+			input = output;
+			current = 1 - current;
+		} // end for pass
+		return Some(input);
+	} // end fn render
+
+} // end impl PostChain
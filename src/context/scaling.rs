@@ -0,0 +1,35 @@
+
+// ScalingMode decides how a virtual resolution is presented inside the window
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScalingMode {
+	Stretch, // Fill the window, ignoring the aspect ratio
+	#[default] Fit, // Keep the aspect ratio, adding letterbox bars
+	Integer, // Only scale by whole numbers, adding letterbox bars
+	Expand, // Keep the aspect ratio, growing the virtual resolution to fill the window
+} // end enum ScalingMode
+
+impl ScalingMode {
+	// Returns the resolution to draw at and the area of the window it is presented in
+	pub fn layout(&self, window: impl Into<Dimensions>, target: impl Into<Dimensions>) -> (Dimensions, BoundingBox) {
+		let window = window.into(); let target = target.into();
+		if window.width <= 0.0 || window.height <= 0.0 || target.width <= 0.0 || target.height <= 0.0 {
+			return (target, BoundingBox::new(0.0, 0.0, window.width.max(0.0), window.height.max(0.0)));
+		} // end if either size is empty
+		let fit = (window.width / target.width).min(window.height / target.height);
+		let scale = match self {
+			ScalingMode::Stretch => { return (target, BoundingBox::new(0.0, 0.0, window.width, window.height)); }
+			ScalingMode::Expand => {
+				let resolution = Dimensions::new(window.width / fit, window.height / fit);
+				return (resolution, BoundingBox::new(0.0, 0.0, window.width, window.height));
+			} // end Expand
+			ScalingMode::Fit => fit,
+			ScalingMode::Integer => fit.floor().max(1.0),
+		}; // end let scale
+		let (width, height) = (target.width * scale, target.height * scale);
+		let (x, y) = (((window.width - width) / 2.0).floor(), ((window.height - height) / 2.0).floor());
+		return (target, BoundingBox::new(x, y, width, height));
+	} // end fn layout
+} // end impl ScalingMode
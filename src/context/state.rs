@@ -1,5 +1,5 @@
 
-use winit::{event::{ElementState, KeyEvent, MouseButton}, keyboard::{KeyCode, PhysicalKey}};
+use winit::{dpi::PhysicalPosition, event::{ElementState, KeyEvent, MouseButton}, keyboard::{KeyCode, PhysicalKey}};
 use std::collections::HashMap;
 
 #[cfg(target_arch = "wasm32")]
@@ -9,10 +9,26 @@ use std::time::Instant;
 
 use crate::prelude::*;
 
+// Physical coordinates are window pixels, logical coordinates are scaled by the scale factor of the monitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordinateSpace {
+	#[default] Physical,
+	Logical,
+} // end enum CoordinateSpace
+
 pub struct State {
 	pub resolution: Dimensions,
+	pub scale_factor: f64,
+	pub coordinates: CoordinateSpace,
+	pub virtual_resolution: Option<Dimensions>,
+	pub scaling: ScalingMode,
+	pub present_rect: BoundingBox, // Area of the window the frame is presented in, in physical pixels, smaller than the window when letterboxed
+	pub render_scale: f64,
 	pub delta: f64,
+	pub time: f64,
 	pub mouse: Option<point::Point>,
+	pub mouse_viewport: Option<usize>, // Index of the split-screen Viewport under the mouse
+	pub viewport_mouse: Option<point::Point>, // Mouse position inside the scene of that viewport
 	pub keys: HashMap<KeyCode, KeyState>,
 	pub wheel: f32,
 	pub wheel_x: f32,
@@ -26,10 +42,20 @@ pub struct State {
 impl State {
 
 	pub fn new ( res: impl Into<Dimensions> ) -> Self {
+		let resolution: Dimensions = res.into();
 		return Self {
-			resolution: res.into(),
+			resolution,
+			scale_factor: 1.0,
+			coordinates: CoordinateSpace::Physical,
+			virtual_resolution: None,
+			scaling: ScalingMode::default(),
+			present_rect: BoundingBox::new(0.0, 0.0, resolution.width, resolution.height),
+			render_scale: 1.0,
 			delta: 0.0,
+			time: 0.0,
 			mouse: None,
+			mouse_viewport: None,
+			viewport_mouse: None,
 			keys: HashMap::new(),
 			wheel: 0.0,
 			wheel_x: 0.0,
@@ -45,6 +71,7 @@ impl State {
 		let now = Instant::now();
 		self.delta = now.duration_since(self.last_time).as_nanos() as f64 / 1_000_000_000.0;
 		self.last_time = now;
+		self.time += self.delta;
 		for key in &mut self.keys {
 			key.1.on_pressed = false;
 			key.1.on_released = false;
@@ -101,6 +128,37 @@ impl State {
 		} } // end if state == Pressed
 	} // end fn button_event
 
+	pub fn cursor_event(&mut self, position: PhysicalPosition<f64>) {
+		self.mouse = Some(self.from_physical((position.x, position.y)));
+	} // end fn cursor_event
+
+	// False while the window is minimized, the coordinate mappings below are then left as the identity
+	fn has_area(&self) -> bool {
+		return self.present_rect.width > 0.0 && self.present_rect.height > 0.0 && self.resolution.width > 0.0 && self.resolution.height > 0.0;
+	} // end fn has_area
+
+	// Number of physical pixels per unit of the current coordinate space
+	pub fn pixel_ratio(&self) -> f64 {
+		if !self.has_area() { return 1.0; }
+		return self.present_rect.width / self.resolution.width;
+	} // end fn pixel_ratio
+
+	pub fn to_physical(&self, point: impl Into<Point>) -> Point {
+		let point = point.into();
+		if !self.has_area() { return point; }
+		let x = point.x * self.present_rect.width / self.resolution.width + self.present_rect.x;
+		let y = point.y * self.present_rect.height / self.resolution.height + self.present_rect.y;
+		return Point { x, y };
+	} // end fn to_physical
+
+	pub fn from_physical(&self, point: impl Into<Point>) -> Point {
+		let point = point.into();
+		if !self.has_area() { return point; }
+		let x = (point.x - self.present_rect.x) * self.resolution.width / self.present_rect.width;
+		let y = (point.y - self.present_rect.y) * self.resolution.height / self.present_rect.height;
+		return Point { x, y };
+	} // end fn from_physical
+
 	pub fn fps(&self) -> f64 { return 1.0 / self.delta; }
 
 } // end impl State
@@ -0,0 +1,49 @@
+
+// Viewport is a sub-rectangle of the window showing its own scene through its own camera transform
+
+use vello::kurbo;
+
+use crate::prelude::*;
+
+pub struct Viewport {
+	pub rect: BoundingBox, // Area of the window, in the coordinates of State::resolution
+	pub camera: Affine, // Transform from scene coordinates to viewport coordinates
+	pub scene: Scene,
+	pub visible: bool,
+} // end struct Viewport
+
+impl Viewport {
+	pub fn new(rect: impl Into<BoundingBox>) -> Self {
+		return Self { rect: rect.into(), camera: Affine::new(), scene: Scene::new(), visible: true };
+	} // end fn new
+	pub fn with_camera(mut self, camera: Affine) -> Self { self.camera = camera; return self; }
+
+	pub fn contains(&self, point: impl Into<Point>) -> bool {
+		let point = point.into();
+		return point.x >= self.rect.x && point.x < self.rect.x + self.rect.width
+			&& point.y >= self.rect.y && point.y < self.rect.y + self.rect.height;
+	} // end fn contains
+
+	// Maps a point in window coordinates into the scene shown by this viewport
+	pub fn to_local(&self, point: impl Into<Point>) -> Point {
+		let point = point.into();
+		let local = self.camera.0.inverse() * kurbo::Point::new(point.x - self.rect.x, point.y - self.rect.y);
+		return Point { x: local.x, y: local.y };
+	} // end fn to_local
+
+	// Maps a point in the scene shown by this viewport back into window coordinates
+	pub fn to_window(&self, point: impl Into<Point>) -> Point {
+		let point = point.into();
+		let window = self.camera.0 * kurbo::Point::new(point.x, point.y);
+		return Point { x: window.x + self.rect.x, y: window.y + self.rect.y };
+	} // end fn to_window
+
+	// Draws the viewport scene into another scene, clipped to the viewport rect
+	pub fn draw(&self, target: &mut vello::Scene, transform: kurbo::Affine) {
+		if !self.visible { return; }
+		let offset = transform * kurbo::Affine::translate((self.rect.x, self.rect.y));
+		target.push_clip_layer(offset, &kurbo::Rect::new(0.0, 0.0, self.rect.width, self.rect.height));
+		target.append(&self.scene.0, Some(offset * self.camera.0));
+		target.pop_layer();
+	} // end fn draw
+} // end impl Viewport
@@ -0,0 +1,87 @@
+
+// Brush struct represents what a shape or text is painted with: a solid color, a gradient or an image pattern
+
+use vello::{kurbo, peniko::{self, color::AlphaColor, ColorStop, Gradient, ImageBrush}};
+
+use crate::prim::{Affine, Color, Point};
+use crate::scene::Image;
+
+pub use peniko::Extend;
+
+#[derive(Clone, Debug)]
+pub struct Brush {
+	pub brush: peniko::Brush,
+	pub transform: Option<Affine>, // Applied to the brush only, on top of the shape transform
+} // end struct Brush
+
+impl Brush {
+	pub fn solid(color: impl Into<Color>) -> Self {
+		return Self { brush: peniko::Brush::Solid(color.into().into()), transform: None };
+	} // end fn solid
+
+	// Gradient stops are (offset, color) pairs with offsets from 0.0 to 1.0
+	pub fn linear<C: Into<Color>>(start: impl Into<Point>, end: impl Into<Point>, stops: impl IntoIterator<Item = (f32, C)>) -> Self {
+		let (start, end) = (start.into(), end.into());
+		let gradient = Gradient::new_linear(kurbo::Point::new(start.x, start.y), kurbo::Point::new(end.x, end.y));
+		return Self::gradient(gradient, stops);
+	} // end fn linear
+	pub fn radial<C: Into<Color>>(center: impl Into<Point>, radius: impl Into<f64>, stops: impl IntoIterator<Item = (f32, C)>) -> Self {
+		let center = center.into();
+		let gradient = Gradient::new_radial(kurbo::Point::new(center.x, center.y), radius.into() as f32);
+		return Self::gradient(gradient, stops);
+	} // end fn radial
+	// Angles are in radians, clockwise from the positive x axis
+	pub fn sweep<C: Into<Color>>(center: impl Into<Point>, start_angle: impl Into<f64>, end_angle: impl Into<f64>, stops: impl IntoIterator<Item = (f32, C)>) -> Self {
+		let center = center.into();
+		let gradient = Gradient::new_sweep(kurbo::Point::new(center.x, center.y), start_angle.into() as f32, end_angle.into() as f32);
+		return Self::gradient(gradient, stops);
+	} // end fn sweep
+	fn gradient<C: Into<Color>>(gradient: Gradient, stops: impl IntoIterator<Item = (f32, C)>) -> Self {
+		let stops: Vec<ColorStop> = stops.into_iter().map(|(offset, color)| {
+			let color: AlphaColor<_> = color.into().into();
+			return ColorStop { offset, color: color.into() };
+		}).collect(); // end let stops
+		return Self { brush: peniko::Brush::Gradient(gradient.with_stops(stops.as_slice())), transform: None };
+	} // end fn gradient
+
+	// Repeats the image over the filled shape, starting at the origin of the shape transform
+	pub fn image(image: &Image) -> Self {
+		let brush = ImageBrush::new(image.image.clone()).with_extend(Extend::Repeat);
+		return Self { brush: peniko::Brush::Image(brush), transform: None };
+	} // end fn image
+
+	// How the brush continues outside the gradient range or the image bounds
+	pub fn with_extend(mut self, extend: Extend) -> Self {
+		match &mut self.brush {
+			peniko::Brush::Gradient(gradient) => { gradient.extend = extend; }
+			peniko::Brush::Image(image) => { image.sampler.x_extend = extend; image.sampler.y_extend = extend; }
+			peniko::Brush::Solid(_) => { }
+		} // end match self.brush
+		return self;
+	} // end fn with_extend
+	pub fn with_transform(mut self, transform: Affine) -> Self { self.transform = Some(transform); return self; }
+	pub fn with_alpha(mut self, alpha: f32) -> Self { self.brush = self.brush.multiply_alpha(alpha); return self; }
+
+	pub(crate) fn kurbo_transform(&self) -> Option<kurbo::Affine> {
+		return self.transform.map(|transform| transform.0);
+	} // end fn kurbo_transform
+} // end impl Brush
+
+// Conversions between types
+
+impl From<Color> for Brush { fn from(color: Color) -> Self { return Brush::solid(color); } }
+impl From<&Color> for Brush { fn from(color: &Color) -> Self { return Brush::solid(*color); } }
+impl From<[u8; 4]> for Brush { fn from(color: [u8; 4]) -> Self { return Brush::solid(color); } }
+impl From<&[u8; 4]> for Brush { fn from(color: &[u8; 4]) -> Self { return Brush::solid(color); } }
+impl From<&mut [u8; 4]> for Brush { fn from(color: &mut [u8; 4]) -> Self { return Brush::solid(color); } }
+impl From<[u8; 3]> for Brush { fn from(color: [u8; 3]) -> Self { return Brush::solid(color); } }
+impl From<&[u8; 3]> for Brush { fn from(color: &[u8; 3]) -> Self { return Brush::solid(color); } }
+impl From<&mut [u8; 3]> for Brush { fn from(color: &mut [u8; 3]) -> Self { return Brush::solid(color); } }
+impl From<&Image> for Brush { fn from(image: &Image) -> Self { return Brush::image(image); } }
+impl From<&Brush> for Brush { fn from(brush: &Brush) -> Self { return brush.clone(); } }
+
+impl<'a> From<&'a Brush> for peniko::BrushRef<'a> {
+	fn from(brush: &'a Brush) -> Self {
+		return (&brush.brush).into();
+	} // end From &Brush
+} // end impl From &Brush for BrushRef
@@ -1,9 +1,10 @@
 
 // Color struct represents an RGBA color
 
-use vello::peniko::{color::AlphaColor, BrushRef};
+use vello::peniko::{color::{AlphaColor, Srgb}, BrushRef};
 
 #[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Color {
 	pub r: u8,
 	pub g: u8,
@@ -49,6 +50,12 @@ impl From<&mut [u8; 3]> for Color {
 	} // end From &mut [u8; 3]
 } // end impl From &mut [u8; 3] for Color
 
+impl From<Color> for AlphaColor<Srgb> {
+	fn from(color: Color) -> Self {
+		return AlphaColor::from_rgba8(color.r, color.g, color.b, color.a);
+	} // end From Color
+} // end impl From Color for AlphaColor
+
 impl<'a> From<Color> for BrushRef<'a> {
 	fn from(color: Color) -> Self {
 		return AlphaColor::from_rgba8(
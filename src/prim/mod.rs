@@ -4,4 +4,5 @@ pub mod point; pub use point::*;
 pub mod vec2; pub use vec2::*;
 pub mod color; pub use color::*;
 pub mod angle; pub use angle::*;
-pub mod bbox; pub use bbox::*;
\ No newline at end of file
+pub mod bbox; pub use bbox::*;
+pub mod brush; pub use brush::*;
\ No newline at end of file
@@ -2,7 +2,7 @@
 
 // Point struct represents a 2D point in space
 
-use vello::kurbo::Vec2;
+use vello::kurbo::{self, Vec2};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point {
@@ -18,3 +18,4 @@ impl From<(i32, i32)> for Point { fn from(coords: (i32, i32)) -> Self { return P
 impl From<(i64, i64)> for Point { fn from(coords: (i64, i64)) -> Self { return Point { x: coords.0 as f64, y: coords.1 as f64 }; } }
 
 impl From<Vec2> for Point { fn from(vec: Vec2) -> Self { return Point { x: vec.x, y: vec.y }; } }
+impl From<kurbo::Point> for Point { fn from(point: kurbo::Point) -> Self { return Point { x: point.x, y: point.y }; } }
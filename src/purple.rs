@@ -1,5 +1,5 @@
 use std::{cell::RefCell, rc::Rc, sync::Arc};
-use winit::{application::ApplicationHandler, event::MouseScrollDelta, event_loop::{ControlFlow, EventLoop}, window::{self, Icon}};
+use winit::{application::ApplicationHandler, event::WindowEvent, event_loop::{ControlFlow, EventLoop}, window::{self, Icon}};
 use crate::prelude::*;
 
 #[cfg(target_os = "windows")]
@@ -16,6 +16,13 @@ pub struct WindowConfig {
 	pub disable_decorations: bool,
 	pub icon: Option<Image>,
 	pub resizeable: bool,
+	pub persisted_geometry: Option<String>,
+	pub coordinates: CoordinateSpace,
+	pub virtual_resolution: Option<Dimensions>,
+	pub scaling: ScalingMode,
+	pub nearest_filtering: bool,
+	pub render_scale: f64,
+	pub hot_reload: bool,
 } // end struct WindowConfig
 
 impl WindowConfig {
@@ -27,6 +34,12 @@ impl WindowConfig {
 	pub fn with_icon(mut self, icon: Option<Image>) -> Self { self.icon = icon; return self; }
 	pub fn with_resizable(mut self, resizeable: bool) -> Self { self.resizeable = resizeable; return self; }
 	pub fn with_decorations(mut self, decorations: bool) -> Self { self.disable_decorations = !decorations; return self; }
+	pub fn with_persisted_geometry(mut self, key: impl Into<String>) -> Self { self.persisted_geometry = Some(key.into()); return self; }
+	pub fn with_coordinate_space(mut self, coordinates: CoordinateSpace) -> Self { self.coordinates = coordinates; return self; }
+	pub fn with_virtual_resolution(mut self, resolution: impl Into<Dimensions>, scaling: ScalingMode) -> Self { self.virtual_resolution = Some(resolution.into()); self.scaling = scaling; return self; }
+	pub fn with_nearest_filtering(mut self, nearest: bool) -> Self { self.nearest_filtering = nearest; return self; }
+	pub fn with_render_scale(mut self, render_scale: f64) -> Self { self.render_scale = render_scale; return self; }
+	pub fn with_hot_reload(mut self, hot_reload: bool) -> Self { self.hot_reload = hot_reload; return self; }
 } // end impl WindowConfig
 
 impl Default for WindowConfig {
@@ -38,6 +51,13 @@ impl Default for WindowConfig {
 		disable_decorations: false,
 		icon: None,
 		resizeable: true,
+		persisted_geometry: None,
+		coordinates: CoordinateSpace::Physical,
+		virtual_resolution: None,
+		scaling: ScalingMode::Fit,
+		nearest_filtering: false,
+		render_scale: 1.0,
+		hot_reload: false,
 	}; } // end fn default
 } // end impl Default
 
@@ -45,13 +65,14 @@ pub struct Purple<F> where F: FnMut(&mut Context) {
 	pub eloop: F,
 	pub config: WindowConfig,
 	pub context: Option<Context>,
+	pub geometry: Option<WindowGeometry>,
 } // end struct Purple
 
 impl<F> Purple<F> where F: FnMut(&mut Context) {
 	pub fn new ( config: WindowConfig, eloop: F ) {
 		let event_loop: EventLoop<()> = EventLoop::new().unwrap();
 		event_loop.set_control_flow(ControlFlow::Wait);
-		let mut purple = Self { eloop, config, context: None };
+		let mut purple = Self { eloop, config, context: None, geometry: None };
 		let _ = event_loop.run_app(&mut purple);
 	} // end fn new
 } // end impl Purple
@@ -67,15 +88,35 @@ impl<F> ApplicationHandler for Purple<F> where F: FnMut(&mut Context) {
 			icon = Some(Icon::from_rgba(icon_data, width as u32, height as u32).unwrap());
 		} // end if let Some(icon_image)
 
+		let mut fullscreen = self.config.fullscreen;
+		let mut resolution = self.config.resolution;
+		let mut position = None;
+		let mut maximized = false;
+		let mut logical = self.config.coordinates == CoordinateSpace::Logical;
+
+		if let Some(key) = &self.config.persisted_geometry {
+			let mut geometry = match WindowGeometry::load(key) {
+				Some(geometry) => { logical = false; geometry } // Saved sizes are always physical
+				None => WindowGeometry::new(resolution),
+			}; // end let mut geometry
+			geometry.validate(event_loop);
+			resolution = geometry.size;
+			position = geometry.position;
+			maximized = geometry.maximized;
+			fullscreen = geometry.fullscreen;
+			self.geometry = Some(geometry);
+		} // end if let Some(key)
+
 		let mut window_attributes = window::WindowAttributes::default()
 		.with_title(self.config.title.as_str())
-		.with_fullscreen(if self.config.fullscreen { FULLSCREEN } else { WINDOWED })
+		.with_fullscreen(if fullscreen { FULLSCREEN } else { WINDOWED })
 		.with_resizable(true)
 		.with_decorations(!self.config.disable_decorations)
 		.with_min_inner_size(winit::dpi::PhysicalSize::new(40, 40))
-		.with_resizable(self.config.resizeable);
+		.with_resizable(self.config.resizeable)
+		.with_maximized(maximized);
 
-		let mut resolution = self.config.resolution;
+		if let Some((x, y)) = position { window_attributes = window_attributes.with_position(winit::dpi::PhysicalPosition::new(x, y)); }
 
 		#[cfg(target_arch = "wasm32")]
 		if let Some(canvas_id) = &self.config.canvas_id {
@@ -83,26 +124,32 @@ impl<F> ApplicationHandler for Purple<F> where F: FnMut(&mut Context) {
 			if let Some(canvas) = crate::web::get_canvas(canvas_id) {
 				if canvas.has_attribute("width") && canvas.has_attribute("height") {
 					resolution = (canvas.width(), canvas.height()).into();
+					logical = false;
 				} window_attributes = window_attributes.with_canvas(Some(canvas));
 			} else { panic!("Could not find specified canvas"); }
 		} else { panic!("The canvas id must be set"); }
 
-		window_attributes = window_attributes.with_inner_size(winit::dpi::PhysicalSize::new(resolution.width, resolution.height));
+		window_attributes = if logical { window_attributes.with_inner_size(winit::dpi::LogicalSize::new(resolution.width, resolution.height)) }
+		else { window_attributes.with_inner_size(winit::dpi::PhysicalSize::new(resolution.width, resolution.height)) };
 		let window = Arc::new(event_loop.create_window(window_attributes).unwrap());
 
 		let ptr = &mut self.context as *mut Option<Context>;
 		#[cfg(target_arch = "wasm32")]
-		{	wasm_bindgen_futures::spawn_local(async move {
+		{	let config = &self.config as *const WindowConfig;
+			wasm_bindgen_futures::spawn_local(async move {
 				let mut result = Context::new(window.clone()).await;
 				result.resize();
+				result.configure(unsafe { &*config });
 				unsafe { *ptr = Some(result); }
 				window.clone().request_redraw();
 			}); // end spawn_local
 		} // end cfg wasm32
 
 		#[cfg(not(target_arch = "wasm32"))]
-		{	async_std::task::block_on ( async move {
-				let result = Context::new(window.clone()).await;
+		{	let config = &self.config;
+			async_std::task::block_on ( async move {
+				let mut result = Context::new(window.clone()).await;
+				result.configure(config);
 				unsafe { *ptr = Some(result); }
 				window.set_window_icon(icon);
 				window.clone().request_redraw();
@@ -113,39 +160,32 @@ impl<F> ApplicationHandler for Purple<F> where F: FnMut(&mut Context) {
 	fn window_event(&mut self,
 		event_loop: &winit::event_loop::ActiveEventLoop,
 		window_id: winit::window::WindowId,
-		event: winit::event::WindowEvent,
+		event: WindowEvent,
 	) { // begin fn window_event
 
 		if self.context.is_none() { return; }
-		let mut context = self.context.as_mut().unwrap();
+		let context = self.context.as_mut().unwrap();
 		if window_id != context.window.id() { return; }
 
 		match event {
 
-			winit::event::WindowEvent::RedrawRequested => {
-				(self.eloop)(&mut context);
-				context.state.update();
-				context.render();
-				context.window.request_redraw();
-				if context.state.exiting { self.context.take(); event_loop.exit(); }
+			WindowEvent::RedrawRequested => {
+				context.frame(&mut self.eloop);
+				if context.state.exiting {
+					if let (Some(key), Some(geometry)) = (&self.config.persisted_geometry, &mut self.geometry) {
+						geometry.track(&context.window);
+						geometry.save(key);
+					} // end if persisted geometry
+					self.context.take(); event_loop.exit();
+				} // end if exiting
 			} // end RedrawRequested
 
-			winit::event::WindowEvent::CloseRequested => { context.state.exiting = true; }
-			winit::event::WindowEvent::Resized(_size) => {
-				if context.window.is_resizable() == false { context.window.set_maximized(false); }
-				else { context.resize(); }
-			} // end Resized
-			winit::event::WindowEvent::CursorMoved { device_id: _, position } => { context.state.mouse = Some((position.x, position.y).into()); }
-			winit::event::WindowEvent::CursorLeft { device_id: _ } => { context.state.mouse = None; }
-			winit::event::WindowEvent::KeyboardInput { device_id: _, event, is_synthetic: _ } => { context.state.keyboard_event(event); }
-			winit::event::WindowEvent::MouseInput { device_id: _, state, button } => { context.state.button_event(button, state); }
-			winit::event::WindowEvent::MouseWheel { device_id: _, delta, phase: _ } => {
-				match delta {
-					MouseScrollDelta::LineDelta(x, y) => { context.state.wheel = y; context.state.wheel_x = x; }
-					MouseScrollDelta::PixelDelta(pos) => { context.state.wheel = pos.y as f32 / 100.0; context.state.wheel_x = pos.x as f32 / 100.0; }
-				} // end match delta
-			} // end MouseWheel
-			_ => {} // default case
+			WindowEvent::Resized(_) | WindowEvent::Moved(_) => {
+				context.handle_window_event(event);
+				if let Some(geometry) = &mut self.geometry { geometry.track(&context.window); }
+			} // end Resized | Moved
+
+			_ => { context.handle_window_event(event); }
 
 		} // end match event
 	} // end fn window_event
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
-use vello::{peniko::{self, Blob, ImageFormat}, wgpu};
+use vello::{kurbo, peniko::{self, Blob, BlendMode, Compose, Fill, ImageBrush, ImageFormat, Mix}, wgpu};
 
-use crate::prim::Dimensions;
+use crate::prim::{Affine, BoundingBox, Color, Dimensions};
+use crate::scene::Scene;
+
+pub use peniko::ImageQuality;
 
 pub struct Image {
 	pub image: peniko::ImageData,
@@ -38,4 +41,47 @@ impl Image {
 	pub fn size(&self) -> Dimensions {
 		Dimensions { width: self.image.width as f64, height: self.image.height as f64 }
 	} // end fn size
-} // end impl Image
\ No newline at end of file
+} // end impl Image
+
+pub struct ImageOptions {
+	pub dest: Option<BoundingBox>, // Defaults to the source size at the origin
+	pub source: Option<BoundingBox>, // Defaults to the whole image
+	pub tint: Option<Color>, // Multiplied with the image colors
+	pub opacity: f32,
+	pub quality: ImageQuality, // ImageQuality::Low samples the nearest pixel, for pixel art
+} // end struct ImageOptions
+
+impl Default for ImageOptions {
+	fn default() -> Self {
+		return Self {
+			dest: None,
+			source: None,
+			tint: None,
+			opacity: 1.0,
+			quality: ImageQuality::Medium,
+		}; // end return
+	} // end fn default
+} // end impl Default for ImageOptions
+
+pub fn draw_image(scene: &mut Scene, image: &Image, transform: Affine, options: ImageOptions) {
+	let source = options.source.unwrap_or(BoundingBox::new(0.0, 0.0, image.image.width, image.image.height));
+	let dest = options.dest.unwrap_or(BoundingBox::new(0.0, 0.0, source.width, source.height));
+	if source.width <= 0.0 || source.height <= 0.0 { return; }
+	let rect = kurbo::Rect::new(dest.x, dest.y, dest.x + dest.width, dest.y + dest.height);
+	let brush_transform = kurbo::Affine::translate((dest.x, dest.y))
+		* kurbo::Affine::scale_non_uniform(dest.width / source.width, dest.height / source.height)
+		* kurbo::Affine::translate((-source.x, -source.y));
+	let brush = ImageBrush::new(image.image.clone())
+		.with_quality(options.quality)
+		.with_alpha(options.opacity);
+	if let Some(tint) = options.tint {
+		scene.0.push_layer(Mix::Normal, 1.0, transform.0, &rect);
+		scene.0.fill(Fill::NonZero, transform.0, &brush, Some(brush_transform), &rect);
+		scene.0.push_layer(BlendMode::new(Mix::Multiply, Compose::SrcAtop), 1.0, transform.0, &rect);
+		scene.0.fill(Fill::NonZero, transform.0, tint, None, &rect);
+		scene.0.pop_layer();
+		scene.0.pop_layer();
+	} else {
+		scene.0.fill(Fill::NonZero, transform.0, &brush, Some(brush_transform), &rect);
+	} // end if let Some(tint)
+} // end fn draw_image
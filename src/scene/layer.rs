@@ -0,0 +1,37 @@
+use vello::{kurbo::{self, Shape}, peniko};
+
+use crate::prim::Affine;
+use crate::scene::Scene;
+
+pub use peniko::{BlendMode, Compose, Mix};
+
+// Layers without a clip cover everything, vello always needs a clip shape
+const UNBOUNDED: kurbo::Rect = kurbo::Rect::new(-1.0e7, -1.0e7, 1.0e7, 1.0e7);
+
+pub struct LayerOptions {
+	pub clip: Option<kurbo::BezPath>, // In the coordinates of the layer transform
+	pub opacity: f32,
+	pub blend: BlendMode, // How the layer is combined with what was drawn under it
+} // end struct LayerOptions
+
+impl Default for LayerOptions {
+	fn default() -> Self {
+		return Self { clip: None, opacity: 1.0, blend: BlendMode::default() };
+	} // end fn default
+} // end impl Default for LayerOptions
+
+impl LayerOptions {
+	pub fn new() -> Self { return Self::default(); }
+	pub fn with_clip(mut self, shape: &impl Shape) -> Self { self.clip = Some(shape.to_path(0.1)); return self; }
+	pub fn with_opacity(mut self, opacity: f32) -> Self { self.opacity = opacity; return self; }
+	pub fn with_blend(mut self, blend: impl Into<BlendMode>) -> Self { self.blend = blend.into(); return self; }
+} // end impl LayerOptions
+
+pub fn push_layer(scene: &mut Scene, options: &LayerOptions, transform: Affine) {
+	let is_clip_only = options.opacity >= 1.0 && options.blend == BlendMode::default();
+	match (&options.clip, is_clip_only) {
+		(Some(clip), true) => scene.0.push_clip_layer(transform.0, clip),
+		(Some(clip), false) => scene.0.push_layer(options.blend, options.opacity, transform.0, clip),
+		(None, _) => scene.0.push_layer(options.blend, options.opacity, transform.0, &UNBOUNDED),
+	} // end match options.clip
+} // end fn push_layer
@@ -1,4 +1,8 @@
 pub mod scene; pub use scene::*;
 pub mod text; pub use text::*;
 pub mod image; pub use image::*;
-pub mod canvas; pub use canvas::*;
\ No newline at end of file
+pub mod canvas; pub use canvas::*;
+pub mod target; pub use target::*;
+pub mod stroke; pub use stroke::*;
+pub mod layer; pub use layer::*;
+pub mod shadow; pub use shadow::*;
\ No newline at end of file
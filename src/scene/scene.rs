@@ -1,23 +1,50 @@
 
-use vello::peniko::ImageBrush;
 use vello::kurbo::Stroke;
 
 use crate::prelude::*;
 
+pub use vello::peniko::Fill as FillRule;
+
 pub struct Scene(pub vello::Scene);
 
 impl Scene {
 	pub fn new() -> Self { return Self(vello::Scene::new()); }
 	pub fn clear(&mut self) { self.0.reset(); }
-	pub fn fill(&mut self, color: impl Into<Color>, transform: Affine, shape: &impl vello::kurbo::Shape) {
-		self.0.fill(vello::peniko::Fill::NonZero, transform.0, color.into(), None, shape);
+	pub fn fill(&mut self, brush: impl Into<Brush>, transform: Affine, shape: &impl vello::kurbo::Shape) {
+		let brush = brush.into();
+		self.0.fill(FillRule::NonZero, transform.0, &brush, brush.kurbo_transform(), shape);
 	} // end fn fill
-	pub fn stroke(&mut self, stroke_width: impl Into<f64>, color: impl Into<Color>, transform: Affine, shape: &impl vello::kurbo::Shape) {
-		self.0.stroke(&Stroke::new(stroke_width.into()), transform.0, color.into(), None, shape);
+	// EvenOdd makes overlapping subpaths cut holes regardless of their direction
+	pub fn fill_with_rule(&mut self, rule: FillRule, brush: impl Into<Brush>, transform: Affine, shape: &impl vello::kurbo::Shape) {
+		let brush = brush.into();
+		self.0.fill(rule, transform.0, &brush, brush.kurbo_transform(), shape);
+	} // end fn fill_with_rule
+	pub fn fill_path(&mut self, brush: impl Into<Brush>, transform: Affine, path: &Path) {
+		self.fill_with_rule(path.fill_rule, brush, transform, path);
+	} // end fn fill_path
+	pub fn stroke(&mut self, stroke_width: impl Into<f64>, brush: impl Into<Brush>, transform: Affine, shape: &impl vello::kurbo::Shape) {
+		let brush = brush.into();
+		self.0.stroke(&Stroke::new(stroke_width.into()), transform.0, &brush, brush.kurbo_transform(), shape);
 	} // end fn stroke
-	// pub fn draw_image(&mut self, image: &Image, transform: Affine) {
-	// 	self.0.draw_image(&ImageBrush::new(image.image.clone()), transform.0);
-	// } // end fn draw_image
+	pub fn stroke_styled(&mut self, style: &StrokeStyle, brush: impl Into<Brush>, transform: Affine, shape: &impl vello::kurbo::Shape) {
+		crate::scene::stroke::stroke_styled(self, style, &brush.into(), transform, shape);
+	} // end fn stroke_styled
+	// Everything drawn inside the closure is clipped, faded and blended as a single group
+	pub fn with_layer(&mut self, options: LayerOptions, transform: Affine, draw: impl FnOnce(&mut Scene)) {
+		self.push_layer(&options, transform);
+		draw(self);
+		self.pop_layer();
+	} // end fn with_layer
+	pub fn push_layer(&mut self, options: &LayerOptions, transform: Affine) {
+		crate::scene::layer::push_layer(self, options, transform);
+	} // end fn push_layer
+	pub fn pop_layer(&mut self) { self.0.pop_layer(); }
+	pub fn draw_shadow(&mut self, rect: impl Into<BoundingBox>, radius: impl Into<f64>, blur: impl Into<f64>, color: impl Into<Color>, offset: impl Into<Vec2>, transform: Affine) {
+		crate::scene::shadow::draw_shadow(self, rect.into(), radius.into(), blur.into(), color.into(), offset.into(), transform);
+	} // end fn draw_shadow
+	pub fn draw_image(&mut self, image: &Image, transform: Affine, options: ImageOptions) {
+		crate::scene::image::draw_image(self, image, transform, options);
+	} // end fn draw_image
 	pub fn draw_scene(&mut self, scene: &Scene, transform: Affine) {
 		self.0.append(&scene.0, Some(transform.0));
 	} // end fn draw_scene
@@ -0,0 +1,17 @@
+use vello::kurbo;
+
+use crate::prim::{Affine, BoundingBox, Color, Vec2};
+use crate::scene::Scene;
+
+// Vello has no filter effects yet, so blur is only available for rounded rects, not as a backdrop blur for layers
+
+// Draws a soft shadow for a rounded rect, blur is the standard deviation of the gaussian in scene units
+pub fn draw_shadow(scene: &mut Scene, rect: BoundingBox, radius: f64, blur: f64, color: Color, offset: Vec2, transform: Affine) {
+	let rect = kurbo::Rect::new(rect.x, rect.y, rect.x + rect.width, rect.y + rect.height) + kurbo::Vec2::new(offset.x, offset.y);
+	let radius = radius.max(0.0).min(rect.width().min(rect.height()) / 2.0);
+	if blur <= 0.0 {
+		scene.0.fill(vello::peniko::Fill::NonZero, transform.0, color, None, &kurbo::RoundedRect::from_rect(rect, radius));
+		return;
+	} // end if blur <= 0.0
+	scene.0.draw_blurred_rounded_rect(transform.0, rect, color.into(), radius, blur);
+} // end fn draw_shadow
@@ -0,0 +1,59 @@
+use vello::kurbo::{self, Shape};
+
+use crate::prim::Affine;
+
+pub use vello::kurbo::{Cap, Join};
+
+pub struct StrokeStyle {
+	pub width: f64,
+	pub join: Join,
+	pub start_cap: Cap,
+	pub end_cap: Cap,
+	pub miter_limit: f64, // Miter joins sharper than this ratio fall back to bevels
+	pub dash_pattern: Vec<f64>, // Alternating dash and gap lengths, solid when empty
+	pub dash_offset: f64,
+} // end struct StrokeStyle
+
+impl Default for StrokeStyle {
+	fn default() -> Self {
+		return Self {
+			width: 1.0,
+			join: Join::Round,
+			start_cap: Cap::Round,
+			end_cap: Cap::Round,
+			miter_limit: 4.0,
+			dash_pattern: Vec::new(),
+			dash_offset: 0.0,
+		}; // end return
+	} // end fn default
+} // end impl Default for StrokeStyle
+
+impl StrokeStyle {
+	pub fn new(width: impl Into<f64>) -> Self { return Self { width: width.into(), ..Default::default() }; }
+	pub fn with_join(mut self, join: Join) -> Self { self.join = join; return self; }
+	pub fn with_caps(mut self, cap: Cap) -> Self { self.start_cap = cap; self.end_cap = cap; return self; }
+	pub fn with_start_cap(mut self, cap: Cap) -> Self { self.start_cap = cap; return self; }
+	pub fn with_end_cap(mut self, cap: Cap) -> Self { self.end_cap = cap; return self; }
+	pub fn with_miter_limit(mut self, limit: impl Into<f64>) -> Self { self.miter_limit = limit.into(); return self; }
+	pub fn with_dashes(mut self, offset: impl Into<f64>, pattern: &[f64]) -> Self { self.dash_offset = offset.into(); self.dash_pattern = pattern.to_vec(); return self; }
+
+	pub fn to_kurbo(&self) -> kurbo::Stroke {
+		return kurbo::Stroke::new(self.width)
+			.with_join(self.join)
+			.with_start_cap(self.start_cap)
+			.with_end_cap(self.end_cap)
+			.with_miter_limit(self.miter_limit)
+			.with_dashes(self.dash_offset, self.valid_dashes().iter().copied());
+	} // end fn to_kurbo
+
+	// Patterns with negative, non-finite or only zero lengths have no period to repeat, they are drawn solid
+	fn valid_dashes(&self) -> &[f64] {
+		let pattern = self.dash_pattern.as_slice();
+		let valid = pattern.iter().all(|length| length.is_finite() && *length >= 0.0) && pattern.iter().sum::<f64>() > 0.0;
+		return if valid && self.dash_offset.is_finite() { pattern } else { &[] };
+	} // end fn valid_dashes
+} // end impl StrokeStyle
+
+pub fn stroke_styled(scene: &mut crate::scene::Scene, style: &StrokeStyle, brush: &crate::prim::Brush, transform: Affine, shape: &impl Shape) {
+	scene.0.stroke(&style.to_kurbo(), transform.0, brush, brush.kurbo_transform(), shape);
+} // end fn stroke_styled
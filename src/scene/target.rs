@@ -0,0 +1,76 @@
+
+use vello::{peniko::color::AlphaColor, wgpu};
+use crate::prelude::*;
+
+// RenderTarget is an offscreen texture a Scene can be rendered into and then drawn as an Image
+// The texture stays registered with the renderer until destroy is called, dropping the target leaks the registration
+pub struct RenderTarget {
+	pub image: Image,
+	pub texture: wgpu::Texture,
+	pub view: wgpu::TextureView,
+	pub size: (u32, u32),
+} // end struct RenderTarget
+
+impl RenderTarget {
+	pub fn new(size: impl Into<Dimensions>, gpu: &mut Gpu) -> Self {
+		let (texture, view) = Self::create_texture(size.into(), gpu);
+		let image = Image { image: gpu.renderer.register_texture(texture.clone()) };
+		let size = (texture.width(), texture.height());
+		return Self { image, texture, view, size };
+	} // end fn new
+
+	fn create_texture(size: Dimensions, gpu: &Gpu) -> (wgpu::Texture, wgpu::TextureView) {
+		let width = (size.width as u32).max(1);
+		let height = (size.height as u32).max(1);
+		let texture = gpu.device.create_texture(&wgpu::TextureDescriptor {
+			size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+			mip_level_count: 1,
+			sample_count: 1,
+			dimension: wgpu::TextureDimension::D2,
+			format: wgpu::TextureFormat::Rgba8Unorm,
+			usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_SRC,
+			label: Some("Render Target"),
+			view_formats: &[],
+		}); // end let texture
+		let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+		return (texture, view);
+	} // end fn create_texture
+
+	// Renders the scene into the target, the image keeps showing the result until the next render
+	pub fn render(&self, scene: &Scene, gpu: &mut Gpu) {
+		let _ = gpu.renderer.render_to_texture(
+			&gpu.device,
+			&gpu.queue,
+			&scene.0,
+			&self.view,
+			&vello::RenderParams {
+				base_color: AlphaColor::TRANSPARENT,
+				width: self.size.0,
+				height: self.size.1,
+				antialiasing_method: vello::AaConfig::Msaa16,
+			} // end RenderParams
+		); // end render_to_texture
+	} // end fn render
+
+	// Replaces the texture with one of the new size, the contents are lost until the next render
+	pub fn resize(&mut self, size: impl Into<Dimensions>, gpu: &mut Gpu) {
+		let size = size.into();
+		if (size.width as u32).max(1) == self.size.0 && (size.height as u32).max(1) == self.size.1 { return; }
+		let (texture, view) = Self::create_texture(size, gpu);
+		gpu.renderer.unregister_texture(self.image.image.clone());
+		self.image = Image { image: gpu.renderer.register_texture(texture.clone()) };
+		self.size = (texture.width(), texture.height());
+		self.texture = texture;
+		self.view = view;
+	} // end fn resize
+
+	// Releases the texture registration, the image can no longer be drawn afterwards
+	pub fn destroy(self, gpu: &mut Gpu) {
+		gpu.renderer.unregister_texture(self.image.image);
+		self.texture.destroy();
+	} // end fn destroy
+
+	pub fn dimensions(&self) -> Dimensions {
+		return Dimensions::new(self.size.0 as f64, self.size.1 as f64);
+	} // end fn dimensions
+} // end impl RenderTarget
@@ -1,14 +1,13 @@
 use crate::scene::Scene;
 use crate::context::FontContext;
 use parley::{AlignmentOptions, BoundingBox, FontFamily, StyleProperty};
-use vello::peniko::color::AlphaColor;
 use crate::prim::*;
 
 pub use parley::Alignment;
 
 pub struct TextOptions {
 	pub font_size: f64,
-	pub color: Option<Color>,
+	pub color: Option<Brush>, // Gradients and images use the text coordinates, the brush transform is not applied
 	pub bbox: Option<crate::prim::BoundingBox>,
 	pub font_names: Option<Vec<String>>,
 	pub alignment: parley::Alignment,
@@ -93,7 +92,7 @@ pub fn draw_text(scene: &mut Scene, text: String, transform: Affine, font_ctx: &
 					let mut drawing = scene.0.draw_glyphs(font)
 					.transform(transform.0)
 					.font_size(options.font_size as f32);
-					if let Some(ref color) = options.color { drawing = drawing.brush(vello::peniko::Brush::Solid(AlphaColor::from_rgba8(color.r, color.g, color.b, color.a))); }
+					if let Some(ref brush) = options.color { drawing = drawing.brush(brush); }
 					drawing.draw(&vello::peniko::Style::Fill(vello::peniko::Fill::NonZero), glyphs.iter().cloned());
 				} parley::PositionedLayoutItem::InlineBox(_inline_box) => { }
 			} // end match item
@@ -0,0 +1,77 @@
+// Boolean operations between shapes, computed on flattened outlines so curves come back as polylines
+
+use i_overlay::core::{fill_rule::FillRule as OverlayFillRule, overlay_rule::OverlayRule};
+use i_overlay::float::single::SingleFloatOverlay;
+use vello::kurbo::{self, PathEl, Shape};
+
+use crate::scene::FillRule;
+use crate::shapes::Path;
+
+const TOLERANCE: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+	Union,
+	Intersection,
+	Difference, // The first shape with the second one cut out of it
+	Xor,
+} // end enum BooleanOp
+
+fn contours(shape: &impl Shape) -> Vec<Vec<[f64; 2]>> {
+	let mut contours: Vec<Vec<[f64; 2]>> = Vec::new();
+	kurbo::flatten(shape.path_elements(TOLERANCE), TOLERANCE, |el| {
+		match el {
+			PathEl::MoveTo(p) => { contours.push(vec![[p.x, p.y]]); }
+			PathEl::LineTo(p) => { if let Some(contour) = contours.last_mut() { contour.push([p.x, p.y]); } }
+			_ => { }
+		} // end match el
+	}); // end kurbo::flatten
+	contours.retain(|contour| contour.len() >= 3);
+	return contours;
+} // end fn contours
+
+// Resolves the contours under their fill rule, holes come back wound against their outer contour so nonzero reads them the same
+fn normalized(contours: Vec<Vec<[f64; 2]>>, rule: FillRule) -> Vec<Vec<[f64; 2]>> {
+	if rule == FillRule::NonZero { return contours; }
+	let empty: Vec<Vec<[f64; 2]>> = Vec::new();
+	return contours.overlay(&empty, OverlayRule::Subject, OverlayFillRule::EvenOdd).into_iter().flatten().collect();
+} // end fn normalized
+
+impl Path {
+	// Any shape is read with the nonzero rule, use boolean_with_rules or the Path methods to honour other rules
+	pub fn boolean(a: &impl Shape, b: &impl Shape, op: BooleanOp) -> Path {
+		return Path::boolean_with_rules(a, FillRule::NonZero, b, FillRule::NonZero, op);
+	} // end fn boolean
+	pub fn boolean_with_rules(a: &impl Shape, rule_a: FillRule, b: &impl Shape, rule_b: FillRule, op: BooleanOp) -> Path {
+		let rule = match op {
+			BooleanOp::Union => OverlayRule::Union,
+			BooleanOp::Intersection => OverlayRule::Intersect,
+			BooleanOp::Difference => OverlayRule::Difference,
+			BooleanOp::Xor => OverlayRule::Xor,
+		}; // end let rule
+		let (a, b) = (normalized(contours(a), rule_a), normalized(contours(b), rule_b));
+		let shapes = a.overlay(&b, rule, OverlayFillRule::NonZero);
+		let mut path = kurbo::BezPath::new();
+		for shape in shapes {
+			for contour in shape {
+				for (i, [x, y]) in contour.iter().copied().enumerate() {
+					if i == 0 { path.move_to((x, y)); } else { path.line_to((x, y)); }
+				} // end for point
+				path.close_path();
+			} // end for contour
+		} // end for shape
+		// Holes come back wound against their outer contour, so both fill rules give the same result
+		return Path::from(path).with_fill_rule(FillRule::EvenOdd);
+	} // end fn boolean_with_rules
+	pub fn union(a: &impl Shape, b: &impl Shape) -> Path { return Path::boolean(a, b, BooleanOp::Union); }
+	pub fn intersection(a: &impl Shape, b: &impl Shape) -> Path { return Path::boolean(a, b, BooleanOp::Intersection); }
+	pub fn difference(a: &impl Shape, b: &impl Shape) -> Path { return Path::boolean(a, b, BooleanOp::Difference); }
+	pub fn xor(a: &impl Shape, b: &impl Shape) -> Path { return Path::boolean(a, b, BooleanOp::Xor); }
+
+	// Between two paths, each read with its own fill rule
+	pub fn boolean_with(&self, other: &Path, op: BooleanOp) -> Path { return Path::boolean_with_rules(self, self.fill_rule, other, other.fill_rule, op); }
+	pub fn union_with(&self, other: &Path) -> Path { return self.boolean_with(other, BooleanOp::Union); }
+	pub fn intersection_with(&self, other: &Path) -> Path { return self.boolean_with(other, BooleanOp::Intersection); }
+	pub fn difference_with(&self, other: &Path) -> Path { return self.boolean_with(other, BooleanOp::Difference); }
+	pub fn xor_with(&self, other: &Path) -> Path { return self.boolean_with(other, BooleanOp::Xor); }
+} // end impl Path
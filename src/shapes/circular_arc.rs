@@ -0,0 +1,24 @@
+use vello::kurbo::{self, Shape};
+
+// An open circular arc, angles are in radians and clockwise from the positive x axis
+pub struct CircularArc(pub kurbo::Arc);
+
+impl CircularArc {
+	pub fn new(cx: impl Into<f64>, cy: impl Into<f64>, radius: impl Into<f64>, start_angle: impl Into<f64>, sweep_angle: impl Into<f64>) -> Self {
+		let radius = radius.into();
+		return Self(kurbo::Arc::new((cx.into(), cy.into()), (radius, radius), start_angle.into(), sweep_angle.into(), 0.0));
+	} // end fn new
+	pub fn abs(cx: impl Into<f64>, cy: impl Into<f64>, radius: impl Into<f64>, start_angle: impl Into<f64>, end_angle: impl Into<f64>) -> Self {
+		let start_angle = start_angle.into();
+		return Self::new(cx, cy, radius, start_angle, end_angle.into() - start_angle);
+	} // end fn abs
+} // end impl CircularArc
+
+impl Shape for CircularArc {
+	type PathElementsIter<'iter> = <kurbo::Arc as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for CircularArc
@@ -0,0 +1,125 @@
+// Path effects that turn an outline into a new Path: offsetting, simplifying, rounding corners and flattening
+
+use vello::kurbo::{self, PathEl, Shape};
+
+use crate::scene::{FillRule, Join};
+use crate::shapes::{BooleanOp, Path};
+
+const TOLERANCE: f64 = 0.05;
+const MIN_TOLERANCE: f64 = 1e-3; // Flattening subdivides by the inverse square root of the tolerance, zero would never finish
+
+// Flattened subpaths as point lists, with whether each one was closed
+fn polylines(shape: &impl Shape, tolerance: f64) -> Vec<(Vec<kurbo::Point>, bool)> {
+	let tolerance = if tolerance.is_finite() { tolerance.max(MIN_TOLERANCE) } else { TOLERANCE };
+	let mut polylines: Vec<(Vec<kurbo::Point>, bool)> = Vec::new();
+	kurbo::flatten(shape.path_elements(tolerance), tolerance, |el| {
+		match el {
+			PathEl::MoveTo(p) => { polylines.push((vec![p], false)); }
+			PathEl::LineTo(p) => { if let Some((points, _)) = polylines.last_mut() { points.push(p); } }
+			PathEl::ClosePath => { if let Some((_, closed)) = polylines.last_mut() { *closed = true; } }
+			_ => { }
+		} // end match el
+	}); // end kurbo::flatten
+	for (points, closed) in polylines.iter_mut() {
+		// A closing line back to the start is implied by the close
+		if *closed && points.len() > 1 && points.first() == points.last() { points.pop(); }
+	} // end for polyline
+	polylines.retain(|(points, _)| points.len() >= 2);
+	return polylines;
+} // end fn polylines
+
+fn from_polylines(polylines: &[(Vec<kurbo::Point>, bool)]) -> kurbo::BezPath {
+	let mut path = kurbo::BezPath::new();
+	for (points, closed) in polylines {
+		for (i, point) in points.iter().enumerate() {
+			if i == 0 { path.move_to(*point); } else { path.line_to(*point); }
+		} // end for point
+		if *closed { path.close_path(); }
+	} // end for polyline
+	return path;
+} // end fn from_polylines
+
+// Ramer-Douglas-Peucker, keeps the points that deviate more than the tolerance from the simplified line
+fn simplify_points(points: &[kurbo::Point], tolerance: f64, keep: &mut [bool], offset: usize) {
+	if points.len() < 3 { return; }
+	let line = kurbo::Line::new(points[0], points[points.len() - 1]);
+	let (mut max_distance, mut max_index) = (0.0, 0);
+	for (i, point) in points.iter().enumerate().skip(1).take(points.len() - 2) {
+		let distance = kurbo::ParamCurveNearest::nearest(&line, *point, 1e-9).distance_sq.sqrt();
+		if distance > max_distance { max_distance = distance; max_index = i; }
+	} // end for point
+	if max_distance <= tolerance { return; }
+	keep[offset + max_index] = true;
+	simplify_points(&points[..=max_index], tolerance, keep, offset);
+	simplify_points(&points[max_index..], tolerance, keep, offset + max_index);
+} // end fn simplify_points
+
+impl Path {
+	// Grows the outline by the distance, or shrinks it when negative
+	pub fn offset(shape: &impl Shape, distance: f64, join: Join) -> Path {
+		return Path::offset_with_rule(shape, FillRule::NonZero, distance, join);
+	} // end fn offset
+	pub fn offset_with_rule(shape: &impl Shape, rule: FillRule, distance: f64, join: Join) -> Path {
+		if distance == 0.0 { return Path::from_shape(shape).with_fill_rule(rule); }
+		let style = kurbo::Stroke::new(distance.abs() * 2.0).with_join(join);
+		let band = kurbo::stroke(shape.path_elements(TOLERANCE), &style, &kurbo::StrokeOpts::default(), TOLERANCE);
+		let op = if distance > 0.0 { BooleanOp::Union } else { BooleanOp::Difference };
+		return Path::boolean_with_rules(shape, rule, &band, FillRule::NonZero, op);
+	} // end fn offset_with_rule
+
+	// Drops points of the flattened outline that are within the tolerance of a straight line
+	pub fn simplify(shape: &impl Shape, tolerance: f64) -> Path {
+		let mut polylines = polylines(shape, TOLERANCE.max(tolerance));
+		for (points, closed) in polylines.iter_mut() {
+			if *closed { points.push(points[0]); }
+			let mut keep = vec![false; points.len()];
+			keep[0] = true; *keep.last_mut().unwrap() = true;
+			simplify_points(points, tolerance, &mut keep, 0);
+			let mut kept = keep.iter();
+			points.retain(|_| *kept.next().unwrap());
+			if *closed { points.pop(); }
+		} // end for polyline
+		return Path::from(from_polylines(&polylines));
+	} // end fn simplify
+
+	// Replaces the corners of the flattened outline with circular arcs, shrunk where the edges are too short
+	pub fn round_corners(shape: &impl Shape, radius: f64) -> Path {
+		let mut path = kurbo::BezPath::new();
+		for (points, closed) in polylines(shape, TOLERANCE) {
+			let count = points.len();
+			let mut started = false;
+			for i in 0..count {
+				let vertex = points[i];
+				let is_end = !closed && (i == 0 || i == count - 1);
+				let (prev, next) = (points[(i + count - 1) % count], points[(i + 1) % count]);
+				let (to_prev, to_next) = (prev - vertex, next - vertex);
+				let turn = std::f64::consts::PI - to_prev.cross(to_next).atan2(to_prev.dot(to_next)).abs();
+				if is_end || radius <= 0.0 || turn.abs() < 1e-6 || to_prev.hypot() == 0.0 || to_next.hypot() == 0.0 {
+					if started { path.line_to(vertex); } else { path.move_to(vertex); started = true; }
+					continue;
+				} // end if corner is not rounded
+				let half_tan = (turn / 2.0).tan();
+				let cut = (radius * half_tan).min(to_prev.hypot() / 2.0).min(to_next.hypot() / 2.0);
+				let (a, b) = (vertex + to_prev.normalize() * cut, vertex + to_next.normalize() * cut);
+				let handle = 4.0 / 3.0 * (turn / 4.0).tan() / half_tan; // Cubic approximation of a circular arc
+				if started { path.line_to(a); } else { path.move_to(a); started = true; }
+				path.curve_to(a + (vertex - a) * handle, b + (vertex - b) * handle, b);
+			} // end for vertex
+			if closed { path.close_path(); }
+		} // end for polyline
+		return Path::from(path);
+	} // end fn round_corners
+
+	// Replaces curves with line segments that stay within the tolerance of them, which is at least MIN_TOLERANCE
+	pub fn flatten(shape: &impl Shape, tolerance: f64) -> Path {
+		return Path::from(from_polylines(&polylines(shape, tolerance)));
+	} // end fn flatten
+} // end impl Path
+
+// The same effects on a path, the result keeps its fill rule since every contour keeps its winding
+impl Path {
+	pub fn offset_by(&self, distance: f64, join: Join) -> Path { return Path::offset_with_rule(self, self.fill_rule, distance, join); }
+	pub fn simplified(&self, tolerance: f64) -> Path { return Path::simplify(self, tolerance).with_fill_rule(self.fill_rule); }
+	pub fn rounded(&self, radius: f64) -> Path { return Path::round_corners(self, radius).with_fill_rule(self.fill_rule); }
+	pub fn flattened(&self, tolerance: f64) -> Path { return Path::flatten(self, tolerance).with_fill_rule(self.fill_rule); }
+} // end impl Path
@@ -0,0 +1,23 @@
+use vello::kurbo::{self, Shape};
+
+pub struct Ellipse(pub kurbo::Ellipse);
+
+impl Ellipse {
+	pub fn new(cx: impl Into<f64>, cy: impl Into<f64>, rx: impl Into<f64>, ry: impl Into<f64>) -> Self {
+		return Self(kurbo::Ellipse::new((cx.into(), cy.into()), (rx.into(), ry.into()), 0.0));
+	} // end fn new
+	// The ellipse inscribed in the rect between the two corners
+	pub fn abs(x0: impl Into<f64>, y0: impl Into<f64>, x1: impl Into<f64>, y1: impl Into<f64>) -> Self {
+		return Self(kurbo::Ellipse::from_rect(kurbo::Rect::new(x0.into(), y0.into(), x1.into(), y1.into())));
+	} // end fn abs
+	pub fn with_rotation(self, rotation: f64) -> Self { return Self(self.0.with_rotation(rotation)); }
+} // end impl Ellipse
+
+impl Shape for Ellipse {
+	type PathElementsIter<'iter> = <kurbo::Ellipse as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for Ellipse
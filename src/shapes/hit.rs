@@ -0,0 +1,82 @@
+// Geometric queries in world space, for hover and click detection on any shape drawn with a transform
+
+use vello::kurbo::{self, ParamCurveNearest, PathEl, Shape};
+
+use crate::prim::{Affine, BoundingBox, Point};
+use crate::scene::FillRule;
+use crate::shapes::Path;
+
+const TOLERANCE: f64 = 0.1;
+
+// Open subpaths are closed first, as the renderer does when it fills them
+fn winding(shape: &impl Shape, point: kurbo::Point) -> i32 {
+	let mut path = kurbo::BezPath::new();
+	let mut open = false;
+	for el in shape.path_elements(TOLERANCE) {
+		match el {
+			PathEl::MoveTo(_) => { if open { path.close_path(); } open = true; }
+			PathEl::ClosePath => { open = false; }
+			_ => { }
+		} // end match el
+		path.push(el);
+	} // end for el
+	if open { path.close_path(); }
+	return path.winding(point);
+} // end fn winding
+
+pub trait HitTest {
+	// Whether the point is inside the filled shape, using the nonzero fill rule, Paths called directly use their own rule
+	fn hit_test(&self, point: impl Into<Point>, transform: Affine) -> bool;
+	fn hit_test_with_rule(&self, point: impl Into<Point>, transform: Affine, rule: FillRule) -> bool;
+	// Whether the point is within a stroke of this width in shape units, plus a tolerance in world units
+	fn hit_test_stroke(&self, point: impl Into<Point>, transform: Affine, stroke_width: f64, tolerance: f64) -> bool;
+	// Distance from the point to the closest point of the outline, in world units
+	fn distance_to(&self, point: impl Into<Point>, transform: Affine) -> f64;
+	fn world_bounds(&self, transform: Affine) -> BoundingBox;
+} // end trait HitTest
+
+impl<S: Shape> HitTest for S {
+	fn hit_test(&self, point: impl Into<Point>, transform: Affine) -> bool {
+		return self.hit_test_with_rule(point, transform, FillRule::NonZero);
+	} // end fn hit_test
+
+	fn hit_test_with_rule(&self, point: impl Into<Point>, transform: Affine, rule: FillRule) -> bool {
+		if transform.0.determinant() == 0.0 { return false; }
+		let point = point.into();
+		let local = transform.0.inverse() * kurbo::Point::new(point.x, point.y);
+		let winding = winding(self, local);
+		return match rule {
+			FillRule::NonZero => winding != 0,
+			FillRule::EvenOdd => winding % 2 != 0,
+		}; // end return match
+	} // end fn hit_test_with_rule
+
+	fn hit_test_stroke(&self, point: impl Into<Point>, transform: Affine, stroke_width: f64, tolerance: f64) -> bool {
+		// Non-uniform scales stretch the stroke unevenly, the average scale is close enough for picking
+		let scale = transform.0.determinant().abs().sqrt();
+		return self.distance_to(point, transform) <= stroke_width * scale / 2.0 + tolerance;
+	} // end fn hit_test_stroke
+
+	fn distance_to(&self, point: impl Into<Point>, transform: Affine) -> f64 {
+		let point = point.into();
+		let point = kurbo::Point::new(point.x, point.y);
+		let path = transform.0 * self.to_path(TOLERANCE);
+		let mut distance_sq = f64::INFINITY;
+		for segment in path.segments() {
+			distance_sq = distance_sq.min(segment.nearest(point, 1e-6).distance_sq);
+		} // end for segment
+		return distance_sq.sqrt();
+	} // end fn distance_to
+
+	fn world_bounds(&self, transform: Affine) -> BoundingBox {
+		let rect = (transform.0 * self.to_path(TOLERANCE)).bounding_box();
+		return BoundingBox::new(rect.x0, rect.y0, rect.width(), rect.height());
+	} // end fn world_bounds
+} // end impl HitTest
+
+impl Path {
+	// Shadows HitTest::hit_test so the path is tested with the same fill rule Scene::fill_path draws it with
+	pub fn hit_test(&self, point: impl Into<Point>, transform: Affine) -> bool {
+		return self.hit_test_with_rule(point, transform, self.fill_rule);
+	} // end fn hit_test
+} // end impl Path
@@ -0,0 +1,22 @@
+use vello::kurbo::{self, Shape};
+
+pub struct Line(pub kurbo::Line);
+
+impl Line {
+	pub fn new(x: impl Into<f64>, y: impl Into<f64>, dx: impl Into<f64>, dy: impl Into<f64>) -> Self {
+		let x = x.into(); let y = y.into();
+		return Self(kurbo::Line::new((x, y), (x + dx.into(), y + dy.into())));
+	} // end fn new
+	pub fn abs(x0: impl Into<f64>, y0: impl Into<f64>, x1: impl Into<f64>, y1: impl Into<f64>) -> Self {
+		return Self(kurbo::Line::new((x0.into(), y0.into()), (x1.into(), y1.into())));
+	} // end fn abs
+} // end impl Line
+
+impl Shape for Line {
+	type PathElementsIter<'iter> = <kurbo::Line as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for Line
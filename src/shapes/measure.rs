@@ -0,0 +1,91 @@
+// Arc length queries along the outline of a shape, for placing objects on curves and animating strokes
+
+use vello::kurbo::{self, ParamCurve, ParamCurveArclen, ParamCurveDeriv, PathSeg, Shape};
+
+use crate::prim::{Point, Vec2};
+use crate::shapes::Path;
+
+const ACCURACY: f64 = 1e-4;
+
+struct Segment {
+	seg: PathSeg,
+	start: f64, // Distance along the whole outline where this segment begins
+	length: f64,
+	continues: bool, // Whether it starts where the previous segment ended
+} // end struct Segment
+
+// Measures the outline once so repeated queries along it are cheap
+pub struct PathMeasure {
+	segments: Vec<Segment>,
+	length: f64,
+} // end struct PathMeasure
+
+impl PathMeasure {
+	pub fn new(shape: &impl Shape) -> Self {
+		let path = shape.to_path(0.1);
+		let mut segments = Vec::new();
+		let mut length = 0.0;
+		let mut previous: Option<kurbo::Point> = None;
+		for seg in path.segments() {
+			let seg_length = seg.arclen(ACCURACY);
+			let continues = previous.is_some_and(|end| end == seg.start());
+			previous = Some(seg.end());
+			segments.push(Segment { seg, start: length, length: seg_length, continues });
+			length += seg_length;
+		} // end for seg
+		return Self { segments, length };
+	} // end fn new
+
+	pub fn length(&self) -> f64 { return self.length; }
+
+	// The segment and its parameter at a distance, clamped to the outline
+	fn locate(&self, distance: f64) -> Option<(&Segment, f64)> {
+		let distance = distance.clamp(0.0, self.length);
+		let index = self.segments.partition_point(|s| s.start + s.length < distance).min(self.segments.len().checked_sub(1)?);
+		let segment = &self.segments[index];
+		if segment.length <= 0.0 { return Some((segment, 0.0)); }
+		let t = segment.seg.inv_arclen(distance - segment.start, ACCURACY);
+		return Some((segment, t));
+	} // end fn locate
+
+	pub fn point_at(&self, distance: f64) -> Option<Point> {
+		let (segment, t) = self.locate(distance)?;
+		let point = segment.seg.eval(t);
+		return Some(Point { x: point.x, y: point.y });
+	} // end fn point_at
+
+	// Unit direction of travel at a distance
+	pub fn tangent_at(&self, distance: f64) -> Option<Vec2> {
+		let (segment, t) = self.locate(distance)?;
+		let deriv = match segment.seg {
+			PathSeg::Line(line) => line.p1 - line.p0,
+			PathSeg::Quad(quad) => quad.deriv().eval(t).to_vec2(),
+			PathSeg::Cubic(cubic) => cubic.deriv().eval(t).to_vec2(),
+		}; // end let deriv
+		let deriv = if deriv.hypot() > 0.0 { deriv.normalize() } else { deriv };
+		return Some(Vec2 { x: deriv.x, y: deriv.y });
+	} // end fn tangent_at
+
+	// The part of the outline between two fractions of its length, from 0.0 to 1.0
+	pub fn trim(&self, start: f64, end: f64) -> Path {
+		let (start, end) = (start.clamp(0.0, 1.0) * self.length, end.clamp(0.0, 1.0) * self.length);
+		let mut path = kurbo::BezPath::new();
+		let mut drawing = false;
+		for segment in &self.segments {
+			let (seg_start, seg_end) = (segment.start, segment.start + segment.length);
+			if seg_end < start || seg_start > end || end <= start { drawing = false; continue; }
+			let t0 = if start > seg_start { segment.seg.inv_arclen(start - seg_start, ACCURACY) } else { 0.0 };
+			let t1 = if end < seg_end { segment.seg.inv_arclen(end - seg_start, ACCURACY) } else { 1.0 };
+			let part = segment.seg.subsegment(t0..t1);
+			if !drawing || !segment.continues || t0 > 0.0 { path.move_to(part.start()); }
+			path.push(part.as_path_el());
+			drawing = true;
+		} // end for segment
+		return Path::from(path);
+	} // end fn trim
+} // end impl PathMeasure
+
+impl Path {
+	pub fn length(&self) -> f64 { return PathMeasure::new(self).length(); }
+	pub fn trim(&self, start: f64, end: f64) -> Path { return PathMeasure::new(self).trim(start, end); }
+} // end impl Path
@@ -1,3 +1,16 @@
 pub mod rect; pub use crate::shapes::rect::*;
 pub mod rounded_rect; pub use crate::shapes::rounded_rect::*;
-pub mod circle; pub use crate::shapes::circle::*;
\ No newline at end of file
+pub mod circle; pub use crate::shapes::circle::*;
+pub mod path; pub use crate::shapes::path::*;
+pub mod svg; pub use crate::shapes::svg::*;
+pub mod ellipse; pub use crate::shapes::ellipse::*;
+pub mod line; pub use crate::shapes::line::*;
+pub mod circular_arc; pub use crate::shapes::circular_arc::*;
+pub mod pie; pub use crate::shapes::pie::*;
+pub mod polygon; pub use crate::shapes::polygon::*;
+pub mod star; pub use crate::shapes::star::*;
+pub mod hit; pub use crate::shapes::hit::*;
+pub mod boolean; pub use crate::shapes::boolean::*;
+pub mod measure; pub use crate::shapes::measure::*;
+pub mod effects; pub use crate::shapes::effects::*;
+pub mod morph; pub use crate::shapes::morph::*;
\ No newline at end of file
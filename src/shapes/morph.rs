@@ -0,0 +1,142 @@
+// Tweening between two outlines, both are resampled to the same number of points per subpath and blended
+
+use vello::kurbo::{self, PathEl, Shape};
+
+use crate::scene::FillRule;
+use crate::shapes::{Path, PathMeasure};
+
+pub const MORPH_SAMPLES: usize = 128;
+
+#[derive(Clone)]
+struct Contour {
+	points: Vec<kurbo::Point>,
+	closed: bool,
+} // end struct Contour
+
+fn subpaths(shape: &impl Shape) -> Vec<kurbo::BezPath> {
+	let mut subpaths: Vec<kurbo::BezPath> = Vec::new();
+	for el in shape.path_elements(0.1) {
+		if let PathEl::MoveTo(_) = el { subpaths.push(kurbo::BezPath::new()); }
+		if let Some(subpath) = subpaths.last_mut() { subpath.push(el); }
+	} // end for el
+	subpaths.retain(|subpath| subpath.elements().len() > 1); // A lone move has no outline to sample
+	return subpaths;
+} // end fn subpaths
+
+// Points evenly spaced along the subpath, so both shapes get matching points regardless of their segments
+fn resample(subpath: &kurbo::BezPath, samples: usize) -> Contour {
+	let closed = subpath.elements().last() == Some(&PathEl::ClosePath);
+	let measure = PathMeasure::new(subpath);
+	let step = measure.length() / if closed { samples } else { samples - 1 } as f64;
+	let points = (0..samples).map(|i| {
+		return measure.point_at(step * i as f64).map_or(kurbo::Point::ZERO, |p| kurbo::Point::new(p.x, p.y));
+	}).collect(); // end let points
+	return Contour { points, closed };
+} // end fn resample
+
+// A contour with no counterpart shrinks into its own center
+fn collapsed(contour: &Contour) -> Contour {
+	let sum = contour.points.iter().fold(kurbo::Vec2::ZERO, |sum, p| sum + p.to_vec2());
+	let center = (sum / contour.points.len() as f64).to_point();
+	return Contour { points: vec![center; contour.points.len()], closed: contour.closed };
+} // end fn collapsed
+
+// Shoelace formula, the sign gives the winding direction
+fn signed_area(points: &[kurbo::Point]) -> f64 {
+	let mut area = 0.0;
+	for (i, p) in points.iter().enumerate() {
+		let q = points[(i + 1) % points.len()];
+		area += p.x * q.y - q.x * p.y;
+	} // end for point
+	return area / 2.0;
+} // end fn signed_area
+
+// Crossing test against the contour as a closed polygon
+fn contains(points: &[kurbo::Point], p: kurbo::Point) -> bool {
+	let mut inside = false;
+	for (i, a) in points.iter().enumerate() {
+		let b = points[(i + 1) % points.len()];
+		if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x) { inside = !inside; }
+	} // end for point
+	return inside;
+} // end fn contains
+
+// Even-odd holes may wind either way, turning each closed contour against the ones around it makes nonzero read the same shape
+// so align can match windings across both shapes without filling in holes
+fn orient_holes(contours: &mut [Contour]) {
+	let depths: Vec<usize> = contours.iter().enumerate().map(|(i, contour)| {
+		return contours.iter().enumerate().filter(|(j, other)| *j != i && contains(&other.points, contour.points[0])).count();
+	}).collect(); // end let depths
+	for (contour, depth) in contours.iter_mut().zip(depths) {
+		if contour.closed && (signed_area(&contour.points) < 0.0) != (depth % 2 == 1) { contour.points.reverse(); }
+	} // end for contour
+} // end fn orient_holes
+
+// Matches the winding of a closed contour to the other one, then rotates its start to the closest point, to avoid twisting
+fn align(from: &Contour, to: &mut Contour) {
+	if !from.closed || !to.closed { return; }
+	if signed_area(&from.points) * signed_area(&to.points) < 0.0 { to.points.reverse(); }
+	let cost = |shift: usize| from.points.iter().enumerate()
+		.map(|(i, p)| (to.points[(i + shift) % to.points.len()] - *p).hypot2())
+		.sum::<f64>();
+	let best = (0..to.points.len()).min_by(|a, b| cost(*a).total_cmp(&cost(*b))).unwrap_or(0);
+	to.points.rotate_left(best);
+} // end fn align
+
+pub struct PathMorph {
+	pairs: Vec<(Contour, Contour)>,
+	rules: (FillRule, FillRule), // The result switches from the first to the second halfway, as open contours do
+} // end struct PathMorph
+
+impl PathMorph {
+	// More samples follow sharp corners more closely
+	pub fn new(from: &impl Shape, to: &impl Shape, samples: usize) -> Self {
+		return Self::with_rules(from, FillRule::NonZero, to, FillRule::NonZero, samples);
+	} // end fn new
+	pub fn with_rules(from: &impl Shape, from_rule: FillRule, to: &impl Shape, to_rule: FillRule, samples: usize) -> Self {
+		let samples = samples.max(3);
+		let mut from: Vec<Contour> = subpaths(from).iter().map(|s| resample(s, samples)).collect();
+		let mut to: Vec<Contour> = subpaths(to).iter().map(|s| resample(s, samples)).collect();
+		if from_rule == FillRule::EvenOdd { orient_holes(&mut from); }
+		if to_rule == FillRule::EvenOdd { orient_holes(&mut to); }
+		let mut pairs = Vec::new();
+		for i in 0..from.len().max(to.len()) {
+			let (a, mut b) = match (from.get(i), to.get(i)) {
+				(Some(a), Some(b)) => (a.clone(), b.clone()),
+				(Some(a), None) => (a.clone(), collapsed(a)),
+				(None, Some(b)) => (collapsed(b), b.clone()),
+				(None, None) => unreachable!(),
+			}; // end let (a, b)
+			align(&a, &mut b);
+			pairs.push((a, b));
+		} // end for i
+		return Self { pairs, rules: (from_rule, to_rule) };
+	} // end fn with_rules
+
+	// The intermediate outline, t is clamped to 0.0..1.0
+	pub fn at(&self, t: f64) -> Path {
+		let t = t.clamp(0.0, 1.0);
+		let mut path = kurbo::BezPath::new();
+		for (from, to) in &self.pairs {
+			for (i, (a, b)) in from.points.iter().zip(&to.points).enumerate() {
+				let point = a.lerp(*b, t);
+				if i == 0 { path.move_to(point); } else { path.line_to(point); }
+			} // end for point
+			let closed = if t < 0.5 { from.closed } else { to.closed };
+			if closed { path.close_path(); }
+		} // end for pair
+		let rule = if t < 0.5 { self.rules.0 } else { self.rules.1 };
+		return Path::from(path).with_fill_rule(rule);
+	} // end fn at
+} // end impl PathMorph
+
+impl Path {
+	// For animations, build a PathMorph once and call at for each frame instead
+	pub fn interpolate(from: &impl Shape, to: &impl Shape, t: f64) -> Path {
+		return PathMorph::new(from, to, MORPH_SAMPLES).at(t);
+	} // end fn interpolate
+	// Between two paths, each read with its own fill rule
+	pub fn interpolate_with(&self, to: &Path, t: f64) -> Path {
+		return PathMorph::with_rules(self, self.fill_rule, to, to.fill_rule, MORPH_SAMPLES).at(t);
+	} // end fn interpolate_with
+} // end impl Path
@@ -0,0 +1,80 @@
+use vello::kurbo::{self, PathEl, Shape};
+
+use crate::prim::Point;
+use crate::scene::FillRule;
+
+#[derive(Clone, Debug)]
+pub struct Path {
+	pub path: kurbo::BezPath,
+	pub fill_rule: FillRule, // Used by Scene::fill_path
+} // end struct Path
+
+fn pt(point: impl Into<Point>) -> kurbo::Point {
+	let point = point.into();
+	return kurbo::Point::new(point.x, point.y);
+} // end fn pt
+
+impl Path {
+	pub fn new() -> Self { return Self::from(kurbo::BezPath::new()); }
+
+	// Builder, every segment starts where the previous one ended
+	pub fn move_to(mut self, point: impl Into<Point>) -> Self { self.path.move_to(pt(point)); return self; }
+	pub fn line_to(mut self, point: impl Into<Point>) -> Self { self.path.line_to(pt(point)); return self; }
+	pub fn quad_to(mut self, control: impl Into<Point>, point: impl Into<Point>) -> Self { self.path.quad_to(pt(control), pt(point)); return self; }
+	pub fn cubic_to(mut self, control1: impl Into<Point>, control2: impl Into<Point>, point: impl Into<Point>) -> Self {
+		self.path.curve_to(pt(control1), pt(control2), pt(point));
+		return self;
+	} // end fn cubic_to
+	// Elliptical arc with the same parameters as the SVG A command, rotation is in radians
+	pub fn arc_to(mut self, radii: impl Into<Point>, rotation: f64, large_arc: bool, sweep: bool, point: impl Into<Point>) -> Self {
+		let radii = radii.into();
+		let to = pt(point);
+		let arc = kurbo::SvgArc { from: self.current_point(), to, radii: kurbo::Vec2::new(radii.x, radii.y), x_rotation: rotation, large_arc, sweep };
+		match kurbo::Arc::from_svg_arc(&arc) {
+			Some(arc) => { arc.to_cubic_beziers(0.1, |p1, p2, p| self.path.curve_to(p1, p2, p)); }
+			None => { self.path.line_to(to); } // Degenerate arcs are straight lines, as in SVG
+		} // end match kurbo::Arc::from_svg_arc
+		return self;
+	} // end fn arc_to
+	pub fn close(mut self) -> Self { self.path.close_path(); return self; }
+	pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self { self.fill_rule = fill_rule; return self; }
+
+	// A polyline through the points, closed into a polygon if requested
+	pub fn from_points<P: Into<Point>>(points: impl IntoIterator<Item = P>, closed: bool) -> Self {
+		let mut path = Path::new();
+		for (i, point) in points.into_iter().enumerate() {
+			path = if i == 0 { path.move_to(point) } else { path.line_to(point) };
+		} // end for point
+		if closed && !path.is_empty() { path = path.close(); }
+		return path;
+	} // end fn from_points
+	pub fn from_shape(shape: &impl Shape) -> Self {
+		return Self::from(shape.to_path(0.1));
+	} // end fn from_shape
+
+	// Where the next segment starts, the origin for an empty path
+	// Read from the elements rather than cached, so edits made directly to path are picked up
+	pub fn current_point(&self) -> kurbo::Point {
+		let elements = self.path.elements();
+		let Some(last) = elements.last() else { return kurbo::Point::ZERO; };
+		if let Some(point) = last.end_point() { return point; }
+		// After a close the subpath starts over at its move
+		return elements.iter().rev().find_map(|el| match el { PathEl::MoveTo(p) => Some(*p), _ => None }).unwrap_or(kurbo::Point::ZERO);
+	} // end fn current_point
+	pub fn is_empty(&self) -> bool { return self.path.elements().is_empty(); }
+} // end impl Path
+
+impl From<kurbo::BezPath> for Path {
+	fn from(path: kurbo::BezPath) -> Self {
+		return Path { path, fill_rule: FillRule::NonZero };
+	} // end fn from
+} // end impl From BezPath for Path
+
+impl Shape for Path {
+	type PathElementsIter<'iter> = <kurbo::BezPath as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.path.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.path.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.path.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.path.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.path.bounding_box() }
+} // end impl Shape for Path
@@ -0,0 +1,24 @@
+use vello::kurbo::{self, Shape};
+
+// A pie slice, or an annular sector when the inner radius is above zero
+pub struct Pie(pub kurbo::CircleSegment);
+
+impl Pie {
+	pub fn new(cx: impl Into<f64>, cy: impl Into<f64>, radius: impl Into<f64>, start_angle: impl Into<f64>, sweep_angle: impl Into<f64>) -> Self {
+		return Self(kurbo::CircleSegment::new((cx.into(), cy.into()), radius.into(), 0.0, start_angle.into(), sweep_angle.into()));
+	} // end fn new
+	pub fn abs(cx: impl Into<f64>, cy: impl Into<f64>, radius: impl Into<f64>, start_angle: impl Into<f64>, end_angle: impl Into<f64>) -> Self {
+		let start_angle = start_angle.into();
+		return Self::new(cx, cy, radius, start_angle, end_angle.into() - start_angle);
+	} // end fn abs
+	pub fn with_inner_radius(mut self, inner_radius: impl Into<f64>) -> Self { self.0.inner_radius = inner_radius.into(); return self; }
+} // end impl Pie
+
+impl Shape for Pie {
+	type PathElementsIter<'iter> = <kurbo::CircleSegment as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for Pie
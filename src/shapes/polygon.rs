@@ -0,0 +1,93 @@
+use vello::kurbo::{self, Shape};
+
+use crate::prim::Point;
+
+fn polyline<P: Into<Point>>(x: f64, y: f64, points: impl IntoIterator<Item = P>, closed: bool) -> kurbo::BezPath {
+	let mut path = kurbo::BezPath::new();
+	for (i, point) in points.into_iter().enumerate() {
+		let point = point.into();
+		let point = kurbo::Point::new(x + point.x, y + point.y);
+		if i == 0 { path.move_to(point); } else { path.line_to(point); }
+	} // end for point
+	if closed && !path.elements().is_empty() { path.close_path(); }
+	return path;
+} // end fn polyline
+
+pub struct Polygon(pub kurbo::BezPath);
+
+impl Polygon {
+	// Points are relative to (x, y)
+	pub fn new<P: Into<Point>>(x: impl Into<f64>, y: impl Into<f64>, points: impl IntoIterator<Item = P>) -> Self {
+		return Self(polyline(x.into(), y.into(), points, true));
+	} // end fn new
+	pub fn abs<P: Into<Point>>(points: impl IntoIterator<Item = P>) -> Self {
+		return Self(polyline(0.0, 0.0, points, true));
+	} // end fn abs
+} // end impl Polygon
+
+pub struct Polyline(pub kurbo::BezPath);
+
+impl Polyline {
+	// Points are relative to (x, y)
+	pub fn new<P: Into<Point>>(x: impl Into<f64>, y: impl Into<f64>, points: impl IntoIterator<Item = P>) -> Self {
+		return Self(polyline(x.into(), y.into(), points, false));
+	} // end fn new
+	pub fn abs<P: Into<Point>>(points: impl IntoIterator<Item = P>) -> Self {
+		return Self(polyline(0.0, 0.0, points, false));
+	} // end fn abs
+} // end impl Polyline
+
+impl Shape for Polygon {
+	type PathElementsIter<'iter> = <kurbo::BezPath as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for Polygon
+
+impl Shape for Polyline {
+	type PathElementsIter<'iter> = <kurbo::BezPath as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for Polyline
+
+pub struct RegularPolygon(pub kurbo::BezPath);
+
+impl RegularPolygon {
+	pub fn new(cx: impl Into<f64>, cy: impl Into<f64>, radius: impl Into<f64>, sides: usize) -> Self {
+		let radius = radius.into();
+		return Self(regular_path(cx.into(), cy.into(), radius, radius, sides));
+	} // end fn new
+	// Stretched to the ellipse inscribed in the rect between the two corners
+	pub fn abs(x0: impl Into<f64>, y0: impl Into<f64>, x1: impl Into<f64>, y1: impl Into<f64>, sides: usize) -> Self {
+		let rect = kurbo::Rect::new(x0.into(), y0.into(), x1.into(), y1.into()).abs();
+		let center = rect.center();
+		return Self(regular_path(center.x, center.y, rect.width() / 2.0, rect.height() / 2.0, sides));
+	} // end fn abs
+} // end impl RegularPolygon
+
+// Corners evenly spaced around the center, the first one pointing up
+fn regular_path(cx: f64, cy: f64, rx: f64, ry: f64, sides: usize) -> kurbo::BezPath {
+	let sides = sides.max(3);
+	let mut path = kurbo::BezPath::new();
+	for i in 0..sides {
+		let angle = -std::f64::consts::FRAC_PI_2 + std::f64::consts::TAU * i as f64 / sides as f64;
+		let point = kurbo::Point::new(cx + rx * angle.cos(), cy + ry * angle.sin());
+		if i == 0 { path.move_to(point); } else { path.line_to(point); }
+	} // end for i
+	path.close_path();
+	return path;
+} // end fn regular_path
+
+impl Shape for RegularPolygon {
+	type PathElementsIter<'iter> = <kurbo::BezPath as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for RegularPolygon
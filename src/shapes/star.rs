@@ -0,0 +1,40 @@
+use vello::kurbo::{self, Shape};
+
+// Points alternating between the outer and inner radius around the center, the first one pointing up
+fn star_path(cx: f64, cy: f64, rx: f64, ry: f64, inner_ratio: f64, count: usize) -> kurbo::BezPath {
+	let mut path = kurbo::BezPath::new();
+	let step = std::f64::consts::PI / count as f64;
+	for i in 0..count * 2 {
+		let ratio = if i % 2 == 0 { 1.0 } else { inner_ratio };
+		let angle = -std::f64::consts::FRAC_PI_2 + step * i as f64;
+		let point = kurbo::Point::new(cx + rx * ratio * angle.cos(), cy + ry * ratio * angle.sin());
+		if i == 0 { path.move_to(point); } else { path.line_to(point); }
+	} // end for i
+	path.close_path();
+	return path;
+} // end fn star_path
+
+pub struct Star(pub kurbo::BezPath);
+
+impl Star {
+	pub fn new(cx: impl Into<f64>, cy: impl Into<f64>, outer_radius: impl Into<f64>, inner_radius: impl Into<f64>, points: usize) -> Self {
+		let outer_radius = outer_radius.into();
+		let inner_ratio = if outer_radius == 0.0 { 0.0 } else { inner_radius.into() / outer_radius };
+		return Self(star_path(cx.into(), cy.into(), outer_radius, outer_radius, inner_ratio, points.max(2)));
+	} // end fn new
+	// Stretched to the ellipse inscribed in the rect, inner_ratio is the inner radius as a fraction of the outer one
+	pub fn abs(x0: impl Into<f64>, y0: impl Into<f64>, x1: impl Into<f64>, y1: impl Into<f64>, inner_ratio: impl Into<f64>, points: usize) -> Self {
+		let rect = kurbo::Rect::new(x0.into(), y0.into(), x1.into(), y1.into()).abs();
+		let center = rect.center();
+		return Self(star_path(center.x, center.y, rect.width() / 2.0, rect.height() / 2.0, inner_ratio.into(), points.max(2)));
+	} // end fn abs
+} // end impl Star
+
+impl Shape for Star {
+	type PathElementsIter<'iter> = <kurbo::BezPath as Shape>::PathElementsIter<'iter> where Self: 'iter;
+	fn path_elements(&self, tolerance: f64) -> Self::PathElementsIter<'_> { self.0.path_elements(tolerance) }
+	fn area(&self) -> f64 { self.0.area() }
+	fn perimeter(&self, accuracy: f64) -> f64 { self.0.perimeter(accuracy) }
+	fn winding(&self, pt: kurbo::Point) -> i32 { self.0.winding(pt) }
+	fn bounding_box(&self) -> kurbo::Rect { self.0.bounding_box() }
+} // end impl Shape for Star
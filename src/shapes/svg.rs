@@ -0,0 +1,215 @@
+// SVG path data, as found in the d attribute of path elements
+
+use std::fmt;
+
+use vello::kurbo;
+
+use crate::shapes::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgPathError {
+	pub position: usize, // Byte offset in the path data
+	pub message: String,
+} // end struct SvgPathError
+
+impl fmt::Display for SvgPathError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		return write!(f, "invalid SVG path data at byte {}: {}", self.position, self.message);
+	} // end fn fmt
+} // end impl Display for SvgPathError
+
+impl std::error::Error for SvgPathError {}
+
+struct Parser<'a> {
+	data: &'a [u8],
+	pos: usize,
+} // end struct Parser
+
+impl<'a> Parser<'a> {
+	fn error<T>(&self, message: impl Into<String>) -> Result<T, SvgPathError> {
+		return Err(SvgPathError { position: self.pos, message: message.into() });
+	} // end fn error
+	fn skip_whitespace(&mut self) {
+		while self.pos < self.data.len() && self.data[self.pos].is_ascii_whitespace() { self.pos += 1; }
+	} // end fn skip_whitespace
+	fn skip_separator(&mut self) {
+		self.skip_whitespace();
+		if self.pos < self.data.len() && self.data[self.pos] == b',' { self.pos += 1; }
+		self.skip_whitespace();
+	} // end fn skip_separator
+	fn peek(&self) -> Option<u8> { return self.data.get(self.pos).copied(); }
+
+	fn number(&mut self) -> Result<f64, SvgPathError> {
+		self.skip_separator();
+		let start = self.pos;
+		if matches!(self.peek(), Some(b'-' | b'+')) { self.pos += 1; }
+		let mut digits = 0;
+		while matches!(self.peek(), Some(b'0'..=b'9')) { self.pos += 1; digits += 1; }
+		if self.peek() == Some(b'.') {
+			self.pos += 1;
+			while matches!(self.peek(), Some(b'0'..=b'9')) { self.pos += 1; digits += 1; }
+		} // end if fraction
+		if digits == 0 {
+			self.pos = start;
+			return match self.peek() {
+				None => self.error("expected a number, found the end of the data"),
+				Some(c) => self.error(format!("expected a number, found '{}'", c as char)),
+			}; // end return match
+		} // end if digits == 0
+		if matches!(self.peek(), Some(b'e' | b'E')) {
+			let mark = self.pos;
+			self.pos += 1;
+			if matches!(self.peek(), Some(b'-' | b'+')) { self.pos += 1; }
+			if !matches!(self.peek(), Some(b'0'..=b'9')) { self.pos = mark; }
+			while matches!(self.peek(), Some(b'0'..=b'9')) { self.pos += 1; }
+		} // end if exponent
+		let text = std::str::from_utf8(&self.data[start..self.pos]).unwrap();
+		return match text.parse::<f64>() {
+			Ok(value) => Ok(value),
+			Err(_) => { self.pos = start; self.error(format!("invalid number '{}'", text)) }
+		}; // end return match
+	} // end fn number
+
+	// Arc flags are a single 0 or 1 and may be written without separators
+	fn flag(&mut self) -> Result<bool, SvgPathError> {
+		self.skip_separator();
+		return match self.peek() {
+			Some(b'0') => { self.pos += 1; Ok(false) }
+			Some(b'1') => { self.pos += 1; Ok(true) }
+			None => self.error("expected an arc flag, found the end of the data"),
+			Some(c) => self.error(format!("expected an arc flag (0 or 1), found '{}'", c as char)),
+		}; // end return match
+	} // end fn flag
+
+	fn point(&mut self, relative: bool, current: kurbo::Point) -> Result<kurbo::Point, SvgPathError> {
+		let (x, y) = (self.number()?, self.number()?);
+		return Ok(if relative { kurbo::Point::new(current.x + x, current.y + y) } else { kurbo::Point::new(x, y) });
+	} // end fn point
+} // end impl Parser
+
+impl Path {
+	pub fn from_svg(data: &str) -> Result<Path, SvgPathError> {
+		let mut parser = Parser { data: data.as_bytes(), pos: 0 };
+		let mut path = Path::new();
+		let mut current = kurbo::Point::ZERO;
+		let mut start = kurbo::Point::ZERO;
+		let mut last_control: Option<(u8, kurbo::Point)> = None; // Reflected by S and T
+		let mut command: Option<u8> = None;
+
+		loop {
+			parser.skip_separator();
+			let Some(c) = parser.peek() else { break; };
+			if c.is_ascii_alphabetic() {
+				if !b"MmLlHhVvCcSsQqTtAaZz".contains(&c) { return parser.error(format!("unknown command '{}'", c as char)); }
+				if command.is_none() && c.to_ascii_uppercase() != b'M' { return parser.error("path data must start with a move command"); }
+				parser.pos += 1;
+				command = Some(c);
+			} else if let Some(previous) = command {
+				// Repeated coordinates reuse the previous command, a move becomes a line
+				if previous.to_ascii_uppercase() == b'Z' { return parser.error(format!("unexpected '{}' after close command", c as char)); }
+				if previous == b'M' { command = Some(b'L'); }
+				if previous == b'm' { command = Some(b'l'); }
+			} else {
+				return parser.error("path data must start with a move command");
+			} // end if c.is_ascii_alphabetic()
+
+			let c = command.unwrap();
+			let relative = c.is_ascii_lowercase();
+			let kind = c.to_ascii_uppercase();
+			let mut control = None;
+			match kind {
+				b'M' => { current = parser.point(relative, current)?; start = current; path = path.move_to(current); }
+				b'L' => { current = parser.point(relative, current)?; path = path.line_to(current); }
+				b'H' => { let x = parser.number()?; current.x = if relative { current.x + x } else { x }; path = path.line_to(current); }
+				b'V' => { let y = parser.number()?; current.y = if relative { current.y + y } else { y }; path = path.line_to(current); }
+				b'C' | b'S' => {
+					let c1 = if kind == b'C' { parser.point(relative, current)? } else {
+						match last_control { Some((b'C' | b'S', p)) => current + (current - p), _ => current }
+					}; // end let c1
+					let c2 = parser.point(relative, current)?;
+					current = parser.point(relative, current)?;
+					path = path.cubic_to(c1, c2, current);
+					control = Some((kind, c2));
+				} // end C and S
+				b'Q' | b'T' => {
+					let c1 = if kind == b'Q' { parser.point(relative, current)? } else {
+						match last_control { Some((b'Q' | b'T', p)) => current + (current - p), _ => current }
+					}; // end let c1
+					current = parser.point(relative, current)?;
+					path = path.quad_to(c1, current);
+					control = Some((kind, c1));
+				} // end Q and T
+				b'A' => {
+					let (rx, ry, rotation) = (parser.number()?, parser.number()?, parser.number()?);
+					let (large_arc, sweep) = (parser.flag()?, parser.flag()?);
+					let to = parser.point(relative, current)?;
+					path = path.arc_to((rx.abs(), ry.abs()), rotation.to_radians(), large_arc, sweep, to);
+					current = to;
+				} // end A
+				_ => { path = path.close(); current = start; }
+			} // end match kind
+			last_control = control;
+		} // end loop
+
+		return Ok(path);
+	} // end fn from_svg
+
+	pub fn to_svg(&self) -> String {
+		return self.path.to_svg();
+	} // end fn to_svg
+} // end impl Path
+
+#[cfg(test)]
+mod tests {
+	use vello::kurbo::{PathEl, Point};
+
+	use crate::shapes::Path;
+
+	fn elements(data: &str) -> Vec<PathEl> {
+		return Path::from_svg(data).unwrap().path.elements().to_vec();
+	} // end fn elements
+
+	fn error_position(data: &str) -> usize {
+		return Path::from_svg(data).unwrap_err().position;
+	} // end fn error_position
+
+	#[test]
+	fn implicit_commands() {
+		let expected = vec![PathEl::MoveTo(Point::new(0.0, 0.0)), PathEl::LineTo(Point::new(10.0, 0.0)), PathEl::LineTo(Point::new(20.0, 5.0))];
+		assert_eq!(elements("M0 0 L10 0 20 5"), expected);
+		assert_eq!(elements("M0 0 10 0 20 5"), expected);
+		assert_eq!(elements("m1 1 2 2"), vec![PathEl::MoveTo(Point::new(1.0, 1.0)), PathEl::LineTo(Point::new(3.0, 3.0))]);
+	} // end fn implicit_commands
+
+	#[test]
+	fn packed_numbers() {
+		assert_eq!(elements("M1.5.5"), vec![PathEl::MoveTo(Point::new(1.5, 0.5))]);
+		assert_eq!(elements("M1e-3-2"), vec![PathEl::MoveTo(Point::new(0.001, -2.0))]);
+		assert_eq!(elements("M-1-2L3,4"), vec![PathEl::MoveTo(Point::new(-1.0, -2.0)), PathEl::LineTo(Point::new(3.0, 4.0))]);
+	} // end fn packed_numbers
+
+	#[test]
+	fn packed_arc_flags() {
+		let path = Path::from_svg("M0 0 a1 1 0 011 1").unwrap();
+		assert!(path.path.elements().iter().skip(1).all(|el| matches!(el, PathEl::CurveTo(..))));
+		let end = path.current_point();
+		assert!((end.x - 1.0).abs() < 1e-9 && (end.y - 1.0).abs() < 1e-9);
+	} // end fn packed_arc_flags
+
+	#[test]
+	fn error_positions() {
+		assert_eq!(error_position("L0 0"), 0);
+		assert_eq!(error_position("M0 0 X1 1"), 5);
+		assert_eq!(error_position("M0 0 Lx"), 6);
+		assert_eq!(error_position("M0 0 L10"), 8);
+		assert_eq!(error_position("M 0 0 a1 1 0 2 1 1 1"), 13);
+		assert_eq!(error_position("M0 0 Z 1"), 7);
+	} // end fn error_positions
+
+	#[test]
+	fn round_trip() {
+		let path = Path::from_svg("M0 0 L10 0 Q15 5 10 10 C5 15 0 10 0 5 Z M20 20 L30 20").unwrap();
+		let parsed = Path::from_svg(&path.to_svg()).unwrap();
+		assert_eq!(parsed.path.elements(), path.path.elements());
+	} // end fn round_trip
+} // end mod tests